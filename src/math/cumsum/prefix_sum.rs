@@ -56,6 +56,38 @@ impl<T: Num + Clone> PrefixSum<T> {
     }
 }
 
+impl<T: Num + Clone + PartialOrd> PrefixSum<T> {
+    /// Returns the largest sum of a non-empty contiguous range of values and
+    /// the range itself, or `None` if there are no values.
+    ///
+    /// This is Kadane's algorithm expressed in terms of the prefix sums: the
+    /// best range ending at `j` starts right after the smallest prefix sum
+    /// before `j`. If there are several best ranges, the first one to end is
+    /// returned, and among those the shortest.
+    pub fn max_subarray(&self) -> Option<(T, std::ops::Range<usize>)> {
+        let mut best: Option<(T, std::ops::Range<usize>)> = None;
+
+        // The smallest prefix sum seen so far and the index it ends at
+        let mut min_prefix = T::zero();
+        let mut min_end = 0;
+
+        for (j, prefix) in self.prefix_sums.iter().enumerate() {
+            let sum = prefix.clone() - min_prefix.clone();
+
+            if best.as_ref().is_none_or(|(best_sum, _)| sum > *best_sum) {
+                best = Some((sum, min_end..j + 1));
+            }
+
+            if *prefix <= min_prefix {
+                min_prefix = prefix.clone();
+                min_end = j + 1;
+            }
+        }
+
+        best
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,4 +120,46 @@ mod tests {
 
         assert_eq!(cumsum.query(1..3), 5.0);
     }
+
+    #[test]
+    fn prefix_sum_signed() {
+        let cumsum = PrefixSum::new(&[3, -5, 2, -1, -4]);
+
+        assert_eq!(cumsum.query(0..2), -2);
+        assert_eq!(cumsum.query(1..5), -8);
+        assert_eq!(cumsum.query(2..3), 2);
+    }
+
+    #[test]
+    fn max_subarray() {
+        let cumsum = PrefixSum::new(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]);
+        assert_eq!(cumsum.max_subarray(), Some((6, 3..7)));
+
+        let cumsum = PrefixSum::new(&[-3, -1, -2]);
+        assert_eq!(cumsum.max_subarray(), Some((-1, 1..2)));
+
+        let cumsum = PrefixSum::new(&[2.5, -1.0, 2.0]);
+        assert_eq!(cumsum.max_subarray(), Some((3.5, 0..3)));
+
+        assert_eq!(PrefixSum::<i32>::new(&[]).max_subarray(), None);
+    }
+
+    #[test]
+    fn max_subarray_matches_naive() {
+        let values = (0..40i64)
+            .map(|i| (i * 7919 + 3) % 23 - 11)
+            .collect::<Vec<_>>();
+        let cumsum = PrefixSum::new(&values);
+        let values = &values;
+
+        let naive = (0..values.len())
+            .flat_map(|i| (i + 1..=values.len()).map(move |j| values[i..j].iter().sum::<i64>()))
+            .max()
+            .unwrap();
+
+        let (sum, range) = cumsum.max_subarray().unwrap();
+
+        assert_eq!(sum, naive);
+        assert_eq!(cumsum.query(range), naive);
+    }
 }
@@ -93,6 +93,8 @@ pub fn line_intersection_point<T: CoordinateNum>(
 
 /// Computes the intersection point of two line segments, if one exists.
 ///
+/// This is a thin wrapper around [`segment_intersection_t`].
+///
 /// # Arguments
 ///
 /// * `a` - The first line segment, given as a pair of points.
@@ -103,28 +105,54 @@ pub fn segment_intersection_point<T: CoordinateNum>(
     b: (Coordinate<T>, Coordinate<T>),
     eps: T,
 ) -> Option<Coordinate<T>> {
-    // First check if the infinite lines intersect
-    let intersection = line_intersection_point(a, b, eps)?;
-
-    // Check if intersection point lies within both segments
-    let in_segment = |p: &Coordinate<T>, seg: &(Coordinate<T>, Coordinate<T>)| {
-        let (min_x, max_x) = if seg.0.x <= seg.1.x {
-            (seg.0.x, seg.1.x)
-        } else {
-            (seg.1.x, seg.0.x)
-        };
-
-        let (min_y, max_y) = if seg.0.y <= seg.1.y {
-            (seg.0.y, seg.1.y)
-        } else {
-            (seg.1.y, seg.0.y)
-        };
-
-        p.x >= min_x - eps && p.x <= max_x + eps && p.y >= min_y - eps && p.y <= max_y + eps
-    };
+    let (t, _) = segment_intersection_t(a, b, eps)?;
+
+    Some(Coordinate::new(
+        a.0.x + t * (a.1.x - a.0.x),
+        a.0.y + t * (a.1.y - a.0.y),
+    ))
+}
 
-    if in_segment(&intersection, &a) && in_segment(&intersection, &b) {
-        Some(intersection)
+/// Computes where two line segments intersect, as the parameters `(t, u)` along
+/// each segment.
+///
+/// The intersection point is `a.0 + t * (a.1 - a.0)`, which is the same as
+/// `b.0 + u * (b.1 - b.0)`. Both parameters are in `[0, 1]` (up to `eps`), so
+/// they can be used to interpolate per-vertex attributes or to determine which
+/// of several segments is hit first.
+///
+/// NOTE: This returns `None` if the segments are parallel or collinear, even if
+///       they overlap.
+///
+/// # Arguments
+///
+/// * `a` - The first line segment, given as a pair of points.
+/// * `b` - The second line segment, given as a pair of points.
+/// * `eps` - A small value (e.g. 1e-9) to help with limited floating point precision.
+pub fn segment_intersection_t<T: CoordinateNum>(
+    a: (Coordinate<T>, Coordinate<T>),
+    b: (Coordinate<T>, Coordinate<T>),
+    eps: T,
+) -> Option<(T, T)> {
+    let (dax, day) = (a.1.x - a.0.x, a.1.y - a.0.y);
+    let (dbx, dby) = (b.1.x - b.0.x, b.1.y - b.0.y);
+
+    // a.0 + t * da = b.0 + u * db
+    // => t * da - u * db = b.0 - a.0
+    let matrix = array![[dax, -dbx, b.0.x - a.0.x], [day, -dby, b.0.y - a.0.y],];
+
+    let mut ans = array![T::zero(), T::zero()];
+    let soln = gauss_jordan(matrix, &mut ans, eps);
+
+    if soln != super::Solution::Unique {
+        return None;
+    }
+
+    let (t, u) = (ans[0], ans[1]);
+    let in_range = |v: T| v >= T::zero() - eps && v <= T::one() + eps;
+
+    if in_range(t) && in_range(u) {
+        Some((t, u))
     } else {
         None
     }
@@ -283,4 +311,58 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn test_segment_intersection_t() {
+        assert_eq!(
+            segment_intersection_t(
+                ((0., 0.).into(), (4., 0.).into()),
+                ((1., -1.).into(), (1., 3.).into()),
+                1e-9
+            ),
+            Some((0.25, 0.25))
+        );
+
+        // Touching at an endpoint
+        assert_eq!(
+            segment_intersection_t(
+                ((0., 0.).into(), (2., 2.).into()),
+                ((2., 2.).into(), (3., 0.).into()),
+                1e-9
+            ),
+            Some((1.0, 0.0))
+        );
+
+        // The infinite lines intersect, but the segments don't
+        assert_eq!(
+            segment_intersection_t(
+                ((0., 0.).into(), (1., 0.).into()),
+                ((2., -1.).into(), (2., 1.).into()),
+                1e-9
+            ),
+            None
+        );
+
+        // Collinear
+        assert_eq!(
+            segment_intersection_t(
+                ((0., 0.).into(), (2., 2.).into()),
+                ((1., 1.).into(), (3., 3.).into()),
+                1e-9
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_segment_intersection_vertical() {
+        assert_eq!(
+            segment_intersection_point(
+                ((3., 0.).into(), (3., 4.).into()),
+                ((0., 2.).into(), (6., 2.).into()),
+                1e-9
+            ),
+            Some((3., 2.).into())
+        );
+    }
 }
@@ -0,0 +1,100 @@
+use ndarray::Array2;
+use num::{One, Zero};
+
+/// Raises a square matrix to the given power using binary exponentiation.
+///
+/// This is useful for evaluating linear recurrences (e.g. Fibonacci numbers or
+/// the number of ways to reach a state after `n` transitions) at huge `n` in
+/// O(k³ log n) time for a k×k matrix. Combine it with [`Mod`](super::Mod) to
+/// keep the entries from overflowing.
+///
+/// Only `Zero` and `One` (and thus addition and multiplication) are required of
+/// the elements, so that `Mod` works as well as the primitive number types.
+/// `matrix_pow(m, 0)` returns the identity matrix.
+///
+/// # Panics
+///
+/// * If the matrix is not square.
+pub fn matrix_pow<T: Zero + One + Clone>(matrix: Array2<T>, mut exponent: u64) -> Array2<T> {
+    let (rows, cols) = matrix.dim();
+    assert_eq!(rows, cols, "Matrix must be square");
+
+    let mut result = Array2::from_shape_fn(
+        (rows, cols),
+        |(y, x)| {
+            if y == x {
+                T::one()
+            } else {
+                T::zero()
+            }
+        },
+    );
+
+    let mut base = matrix;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mat_mul(&result, &base);
+        }
+
+        exponent >>= 1;
+
+        if exponent > 0 {
+            base = mat_mul(&base, &base);
+        }
+    }
+
+    result
+}
+
+// ndarray's `dot` requires `LinalgScalar`, which includes `Copy` and `Div`, so
+// we do the multiplication ourselves.
+fn mat_mul<T: Zero + One + Clone>(a: &Array2<T>, b: &Array2<T>) -> Array2<T> {
+    let n = a.nrows();
+
+    Array2::from_shape_fn((n, n), |(y, x)| {
+        (0..n).fold(T::zero(), |acc, k| {
+            acc + a[(y, k)].clone() * b[(k, x)].clone()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+
+    use super::*;
+    use crate::math::Mod;
+
+    #[test]
+    fn test_matrix_pow_fibonacci() {
+        let fib = array![[1u64, 1], [1, 0]];
+
+        assert_eq!(matrix_pow(fib.clone(), 0), array![[1, 0], [0, 1]]);
+        assert_eq!(matrix_pow(fib.clone(), 1), fib);
+        assert_eq!(matrix_pow(fib.clone(), 10)[(0, 1)], 55);
+        assert_eq!(matrix_pow(fib, 90)[(0, 1)], 2_880_067_194_370_816_120);
+    }
+
+    #[test]
+    fn test_matrix_pow_modular() {
+        type M = Mod<1_000_000_007>;
+
+        let fib = array![[M::from(1), M::from(1)], [M::from(1), M::from(0)]];
+
+        // F(10^18) mod 1e9+7
+        let result = matrix_pow(fib, 1_000_000_000_000_000_000);
+        assert_eq!(result[(0, 1)], M::from(209_783_453));
+    }
+
+    #[test]
+    fn test_matrix_pow_matches_naive() {
+        let m = array![[1i64, 2, 0], [0, 1, 3], [4, 0, 1]];
+        let mut naive = matrix_pow(m.clone(), 0);
+
+        for e in 1..=8 {
+            naive = naive.dot(&m);
+            assert_eq!(matrix_pow(m.clone(), e), naive);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod cumsum;
 pub mod gauss_jordan;
 pub mod line_line_intersection;
+pub mod matrix_pow;
 pub mod modular;
 pub mod polygons;
 pub mod polynomials;
@@ -8,6 +9,7 @@ pub mod polynomials;
 pub use cumsum::*;
 pub use gauss_jordan::*;
 pub use line_line_intersection::*;
+pub use matrix_pow::*;
 pub use modular::*;
 pub use polygons::*;
 pub use polynomials::*;
@@ -1,7 +1,13 @@
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
 use num::integer::gcd;
-use num::{Integer, Unsigned};
+use num::{Integer, Num, One, Unsigned, Zero};
+
+use num_modular::{ModularRefOps, ModularUnaryOps};
 
-use num_modular::ModularRefOps;
+use crate::prelude::HashMap;
 
 pub struct Congruence<T: Integer + Unsigned> {
     pub a: T,
@@ -33,6 +39,374 @@ pub fn chinese_remainder_theorem<T: Clone + Integer + Unsigned + ModularRefOps>(
     Some(solution)
 }
 
+/// Discrete logarithm
+///
+/// Finds the smallest non-negative `x` such that `base^x ≡ target (mod
+/// modulus)` using the baby-step giant-step algorithm in O(√modulus) time and
+/// space. Returns `None` if there is no such `x`.
+///
+/// `base` does not need to be coprime to `modulus`.
+///
+/// Reference: https://cp-algorithms.com/algebra/discrete-log.html
+///
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    assert!(modulus != 0, "Modulus must not be 0");
+
+    let mul = |a: u64, b: u64, m: u64| ((a as u128 * b as u128) % m as u128) as u64;
+
+    let mut m = modulus;
+    let mut a = base % m;
+    let mut b = target % m;
+
+    if m == 1 || b == 1 % m {
+        return Some(0);
+    }
+
+    // Divide out common factors of the base and the modulus, so that the
+    // remaining problem has a base that is coprime to the modulus.
+    let mut k = 1 % m;
+    let mut steps = 0;
+
+    loop {
+        let g = gcd(a, m);
+
+        if g == 1 {
+            break;
+        }
+
+        if b == k {
+            return Some(steps);
+        }
+
+        if !b.is_multiple_of(g) {
+            return None;
+        }
+
+        b /= g;
+        m /= g;
+        steps += 1;
+        k = mul(k, a / g, m);
+        a %= m;
+    }
+
+    if b == k {
+        return Some(steps);
+    }
+
+    let n = (m as f64).sqrt() as u64 + 1;
+
+    // Baby steps: b * a^q -> q. Later (larger) q overwrite earlier ones, which
+    // makes the resulting exponent as small as possible.
+    let mut baby_steps = HashMap::default();
+    let mut cur = b;
+
+    for q in 0..=n {
+        baby_steps.insert(cur, q);
+        cur = mul(cur, a, m);
+    }
+
+    // Giant steps: k * a^(n * p)
+    let mut a_n = 1 % m;
+
+    for _ in 0..n {
+        a_n = mul(a_n, a, m);
+    }
+
+    let mut cur = k;
+
+    for p in 1..=n {
+        cur = mul(cur, a_n, m);
+
+        if let Some(q) = baby_steps.get(&cur) {
+            return Some(n * p - q + steps);
+        }
+    }
+
+    None
+}
+
+/// An integer modulo `M`
+///
+/// The arithmetic operators reduce their result modulo `M`, using `u128`
+/// intermediates so that they can't overflow. This is handy for puzzles that
+/// ask for a large count modulo e.g. 1_000_000_007.
+///
+/// `Mod` implements `num::Num`, so it can be used with generic code such as
+/// the polynomial helpers. Division multiplies by the inverse, so it is only
+/// defined for divisors that are coprime to `M`. A modulus of 0 is rejected at
+/// compile time.
+///
+/// # Example
+///
+/// ```
+/// # use utility_belt::prelude::*;
+/// let a = Mod::<7>::new(5);
+/// assert_eq!((a + a).value(), 3);
+/// assert_eq!((a * a.inv().unwrap()).value(), 1);
+/// ```
+///
+/// A modulus of 0 does not compile:
+///
+/// ```compile_fail
+/// # use utility_belt::prelude::*;
+/// let a = Mod::<0>::new(5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mod<const M: u64>(u64);
+
+impl<const M: u64> Mod<M> {
+    // Every operation goes through this constant, so that `Mod<0>` fails to
+    // compile instead of panicking at runtime.
+    const MODULUS: u64 = {
+        assert!(M > 0, "The modulus must be greater than 0");
+        M
+    };
+
+    /// Creates a new value, reducing `value` modulo `M`.
+    pub fn new(value: u64) -> Self {
+        Self(value % Self::MODULUS)
+    }
+
+    /// Returns the value in the range `[0, M)`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Raises the value to the given power using binary exponentiation.
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::new(1);
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+
+            base *= base;
+            exp >>= 1;
+        }
+
+        result
+    }
+
+    /// Returns the multiplicative inverse, or `None` if the value is not
+    /// coprime to `M`.
+    pub fn inv(self) -> Option<Self> {
+        self.0.invm(&Self::MODULUS).map(Self)
+    }
+}
+
+impl<const M: u64> Default for Mod<M> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const M: u64> From<u64> for Mod<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> Display for Mod<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const M: u64> Add for Mod<M> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(((self.0 as u128 + other.0 as u128) % Self::MODULUS as u128) as u64)
+    }
+}
+
+impl<const M: u64> Sub for Mod<M> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const M: u64> Mul for Mod<M> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(((self.0 as u128 * other.0 as u128) % Self::MODULUS as u128) as u64)
+    }
+}
+
+impl<const M: u64> Div for Mod<M> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of `other`.
+    ///
+    /// Panics if `other` is not coprime to `M`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other
+            .inv()
+            .expect("The divisor must be coprime to the modulus")
+    }
+}
+
+impl<const M: u64> Rem for Mod<M> {
+    type Output = Self;
+
+    /// Returns the remainder of the division `self / other`, which is always
+    /// 0 because the division is exact.
+    ///
+    /// Panics if `other` is not coprime to `M`.
+    fn rem(self, other: Self) -> Self {
+        assert!(
+            other.inv().is_some(),
+            "The divisor must be coprime to the modulus"
+        );
+
+        Self::zero()
+    }
+}
+
+impl<const M: u64> Neg for Mod<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Self(Self::MODULUS - self.0)
+        }
+    }
+}
+
+impl<const M: u64> AddAssign for Mod<M> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const M: u64> SubAssign for Mod<M> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const M: u64> MulAssign for Mod<M> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const M: u64> DivAssign for Mod<M> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<const M: u64> Zero for Mod<M> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const M: u64> One for Mod<M> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const M: u64> Num for Mod<M> {
+    type FromStrRadixErr = ParseIntError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        u64::from_str_radix(s, radix).map(Self::new)
+    }
+}
+
+/// Precomputed factorials and inverse factorials modulo a prime
+///
+/// After an O(max_n) setup, `factorial`, `choose` and `permutations` are O(1)
+/// for every `n <= max_n`.
+///
+/// NOTE: The modulus must be a prime larger than `max_n`, so that all
+///       factorials up to `max_n!` are invertible. `new` panics if `max_n!` is
+///       not invertible.
+pub struct Combinatorics {
+    modulus: u64,
+    factorials: Vec<u64>,
+    inv_factorials: Vec<u64>,
+}
+
+impl Combinatorics {
+    pub fn new(max_n: usize, modulus: u64) -> Self {
+        assert!(modulus > 1, "Modulus must be greater than 1");
+
+        let mul = |a: u64, b: u64| ((a as u128 * b as u128) % modulus as u128) as u64;
+
+        let mut factorials = vec![1 % modulus; max_n + 1];
+
+        for i in 1..=max_n {
+            factorials[i] = mul(factorials[i - 1], i as u64);
+        }
+
+        let mut inv_factorials = vec![0; max_n + 1];
+        inv_factorials[max_n] = factorials[max_n]
+            .invm(&modulus)
+            .expect("max_n! must be invertible modulo the modulus");
+
+        for i in (1..=max_n).rev() {
+            inv_factorials[i - 1] = mul(inv_factorials[i], i as u64);
+        }
+
+        Self {
+            modulus,
+            factorials,
+            inv_factorials,
+        }
+    }
+
+    /// Returns `n!` modulo the modulus.
+    ///
+    /// Panics if `n` is larger than `max_n`.
+    pub fn factorial(&self, n: usize) -> u64 {
+        self.factorials[n]
+    }
+
+    /// Returns the number of ways to choose `k` out of `n` items, or 0 if
+    /// `k > n`.
+    ///
+    /// Panics if `n` is larger than `max_n`.
+    pub fn choose(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+
+        self.mul(self.permutations(n, k), self.inv_factorials[k])
+    }
+
+    /// Returns the number of ordered arrangements of `k` out of `n` items, or
+    /// 0 if `k > n`.
+    ///
+    /// Panics if `n` is larger than `max_n`.
+    pub fn permutations(&self, n: usize, k: usize) -> u64 {
+        if k > n {
+            return 0;
+        }
+
+        self.mul(self.factorials[n], self.inv_factorials[n - k])
+    }
+
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        ((a as u128 * b as u128) % self.modulus as u128) as u64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,4 +427,138 @@ mod tests {
         // 2024 Day 14
         assert_eq!(chinese_remainder_theorem(&congruences), Some(6446));
     }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(discrete_log(2, 3, 5), Some(3));
+        assert_eq!(discrete_log(3, 13, 17), Some(4));
+        assert_eq!(discrete_log(2, 3, 7), None);
+        assert_eq!(discrete_log(5, 1, 7), Some(0));
+        assert_eq!(discrete_log(2, 0, 8), Some(3));
+        assert_eq!(discrete_log(2, 1, 1), Some(0));
+    }
+
+    #[test]
+    fn test_discrete_log_brute_force() {
+        for modulus in 1..40u64 {
+            for base in 0..modulus {
+                for target in 0..modulus {
+                    let mut expected = None;
+                    let mut cur = 1 % modulus;
+
+                    for x in 0..(2 * modulus) {
+                        if cur == target {
+                            expected = Some(x);
+                            break;
+                        }
+
+                        cur = cur * base % modulus;
+                    }
+
+                    assert_eq!(
+                        discrete_log(base, target, modulus),
+                        expected,
+                        "{}^x = {} (mod {})",
+                        base,
+                        target,
+                        modulus
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_arithmetic() {
+        type M = Mod<1_000_000_007>;
+
+        let a = M::new(1_000_000_006);
+        let b = M::new(5);
+
+        assert_eq!((a + b).value(), 4);
+        assert_eq!((b - a).value(), 6);
+        assert_eq!((a * a).value(), 1);
+        assert_eq!((-b).value(), 1_000_000_002);
+        assert_eq!((-M::zero()).value(), 0);
+        assert_eq!(M::new(2_000_000_014), M::zero());
+
+        let mut c = b;
+        c += b;
+        c *= b;
+        c -= M::one();
+        assert_eq!(c.value(), 49);
+    }
+
+    #[test]
+    fn test_mod_pow_and_inv() {
+        type M = Mod<13>;
+
+        assert_eq!(M::new(2).pow(12), M::one());
+        assert_eq!(M::new(0).pow(0), M::one());
+        assert_eq!(M::new(3).pow(3).value(), 1);
+
+        for x in 1..13 {
+            let x = M::new(x);
+            assert_eq!(x * x.inv().unwrap(), M::one());
+        }
+
+        assert_eq!(M::zero().inv(), None);
+        assert_eq!(Mod::<12>::new(4).inv(), None);
+        assert_eq!(Mod::<1>::new(5).pow(3).value(), 0);
+    }
+
+    #[test]
+    fn test_mod_division() {
+        type M = Mod<13>;
+
+        assert_eq!(M::new(6) / M::new(4), M::new(8));
+        assert_eq!(M::new(6) % M::new(4), M::zero());
+
+        let mut x = M::new(1);
+        x /= M::new(2);
+        assert_eq!(x * M::new(2), M::one());
+
+        assert_eq!(M::from_str_radix("15", 10), Ok(M::new(2)));
+        assert!(M::from_str_radix("x", 10).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "The divisor must be coprime to the modulus")]
+    fn test_mod_division_by_non_unit() {
+        let _ = Mod::<12>::new(5) / Mod::<12>::new(4);
+    }
+
+    #[test]
+    fn test_combinatorics() {
+        let c = Combinatorics::new(100, 1_000_000_007);
+
+        assert_eq!(c.factorial(0), 1);
+        assert_eq!(c.factorial(10), 3_628_800);
+        assert_eq!(c.factorial(100), 437_918_130);
+
+        assert_eq!(c.choose(5, 2), 10);
+        assert_eq!(c.choose(5, 0), 1);
+        assert_eq!(c.choose(5, 5), 1);
+        assert_eq!(c.choose(2, 5), 0);
+        assert_eq!(c.choose(100, 50), 538_992_043);
+
+        assert_eq!(c.permutations(5, 2), 20);
+        assert_eq!(c.permutations(5, 0), 1);
+        assert_eq!(c.permutations(3, 4), 0);
+    }
+
+    #[test]
+    fn test_combinatorics_small_prime() {
+        let c = Combinatorics::new(6, 7);
+
+        // Pascal's triangle mod 7
+        for n in 1..=6 {
+            for k in 1..n {
+                assert_eq!(
+                    c.choose(n, k),
+                    (c.choose(n - 1, k - 1) + c.choose(n - 1, k)) % 7
+                );
+            }
+        }
+    }
 }
@@ -1,13 +1,15 @@
-use num::{Num, Signed};
+use std::ops::Neg;
+
+use num::Num;
 
 /// Returns the difference between each successive value of the given slice.
-pub fn differences<T: Num + Signed + Clone>(xs: &[T]) -> Vec<T> {
+pub fn differences<T: Num + Neg<Output = T> + Clone>(xs: &[T]) -> Vec<T> {
     xs.windows(2).map(|x| x[1].clone() - x[0].clone()).collect()
 }
 
 /// Determines the degree of the polynomial that generates the given sequence.
 /// Assumes that the sequence does not contain noise.
-pub fn polynomial_degree<T: Num + Signed + Clone>(ys: &[T]) -> usize {
+pub fn polynomial_degree<T: Num + Neg<Output = T> + Clone>(ys: &[T]) -> usize {
     let mut cur = differences(ys);
 
     for degree in 0..(ys.len() - 1) {
@@ -29,7 +31,7 @@ pub fn polynomial_degree<T: Num + Signed + Clone>(ys: &[T]) -> usize {
 /// returned coefficients (a, b, c) correspond to the polynomial `ax² + bx + c`.
 ///
 /// Reference: https://www.radfordmathematics.com/algebra/sequences-series/difference-method-sequences/quadratic-sequences.html
-pub fn fit_quadratic<T: Num + Signed + Clone>(ys: &[T]) -> Option<(T, T, T)> {
+pub fn fit_quadratic<T: Num + Neg<Output = T> + Clone>(ys: &[T]) -> Option<(T, T, T)> {
     let first_diffs = differences(ys);
     let second_diffs = differences(&first_diffs);
     let third_diffs = differences(&second_diffs);
@@ -54,7 +56,7 @@ pub fn fit_quadratic<T: Num + Signed + Clone>(ys: &[T]) -> Option<(T, T, T)> {
 /// cx + d.`
 ///
 /// Reference: https://www.radfordmathematics.com/algebra/sequences-series/difference-method-sequences/cubic-sequences.html
-pub fn fit_cubic<T: Num + Signed + Clone>(ys: &[T]) -> Option<(T, T, T, T)> {
+pub fn fit_cubic<T: Num + Neg<Output = T> + Clone>(ys: &[T]) -> Option<(T, T, T, T)> {
     let first_diffs = differences(ys);
     let second_diffs = differences(&first_diffs);
     let third_diffs = differences(&second_diffs);
@@ -78,8 +80,46 @@ pub fn fit_cubic<T: Num + Signed + Clone>(ys: &[T]) -> Option<(T, T, T, T)> {
     Some((a, b, c, d))
 }
 
+/// Extends a polynomial sequence by `steps` terms using Newton's forward
+/// differences and returns the last new term, so that `steps = 1` returns the
+/// next value of the sequence. Assumes that the sequence does not contain noise.
+///
+/// Panics if `ys` is empty.
+pub fn extrapolate<T: Num + Neg<Output = T> + Clone>(ys: &[T], steps: usize) -> T {
+    assert!(!ys.is_empty(), "Cannot extrapolate an empty sequence");
+
+    // The last value of each row of the difference table
+    let mut lasts = vec![ys[ys.len() - 1].clone()];
+    let mut cur = differences(ys);
+
+    while !cur.is_empty() && cur.iter().any(|x| *x != T::zero()) {
+        lasts.push(cur[cur.len() - 1].clone());
+        cur = differences(&cur);
+    }
+
+    for _ in 0..steps {
+        for k in (0..lasts.len() - 1).rev() {
+            lasts[k] = lasts[k].clone() + lasts[k + 1].clone();
+        }
+    }
+
+    lasts.swap_remove(0)
+}
+
+/// Like `extrapolate`, but extends the sequence towards the front, so that
+/// `steps = 1` returns the value before the first one.
+///
+/// Panics if `ys` is empty.
+pub fn extrapolate_backwards<T: Num + Neg<Output = T> + Clone>(ys: &[T], steps: usize) -> T {
+    let reversed = ys.iter().rev().cloned().collect::<Vec<_>>();
+
+    extrapolate(&reversed, steps)
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::math::Mod;
+
     use super::*;
 
     fn constant_sequence() -> impl Iterator<Item = i8> {
@@ -160,4 +200,29 @@ mod tests {
         assert_eq!(c1, c2);
         assert_eq!(d1, d2);
     }
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15], 1), 18);
+        assert_eq!(extrapolate(&[1, 3, 6, 10, 15, 21], 1), 28);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], 1), 68);
+        assert_eq!(extrapolate(&[5], 3), 5);
+        assert_eq!(extrapolate(&[1, 3, 6], 0), 6);
+
+        let ys = quadratic_sequence().take(20).collect::<Vec<_>>();
+        assert_eq!(extrapolate(&ys[..5], 15), ys[19]);
+        // Works with modular integers, too
+        let ys = [1, 3, 6, 10, 15, 21].map(Mod::<7>::new);
+        assert_eq!(extrapolate(&ys, 1), Mod::new(28));
+    }
+
+    #[test]
+    fn test_extrapolate_backwards() {
+        assert_eq!(extrapolate_backwards(&[0, 3, 6, 9, 12, 15], 1), -3);
+        assert_eq!(extrapolate_backwards(&[1, 3, 6, 10, 15, 21], 1), 0);
+        assert_eq!(extrapolate_backwards(&[10, 13, 16, 21, 30, 45], 1), 5);
+
+        let ys = quadratic_sequence().take(20).collect::<Vec<_>>();
+        assert_eq!(extrapolate_backwards(&ys[15..], 15), ys[0]);
+    }
 }
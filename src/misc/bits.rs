@@ -0,0 +1,90 @@
+/// Returns the `n`-th value of the binary reflected Gray code.
+///
+/// Consecutive values differ in exactly one bit.
+pub fn gray_code(n: u32) -> u32 {
+    n ^ (n >> 1)
+}
+
+/// Returns an iterator over all `2^bits` values with the given number of bits
+/// in Gray code order, starting at 0.
+///
+/// Each value differs from the previous one in exactly one bit, which is
+/// useful for enumerating subsets while only adding or removing one element
+/// per step.
+///
+/// Panics if `bits` is larger than 32.
+pub fn gray_code_sequence(bits: u32) -> impl Iterator<Item = u32> {
+    assert!(bits <= 32, "Gray code sequences are limited to 32 bits");
+
+    (0..1u64 << bits).map(|n| gray_code(n as u32))
+}
+
+/// Returns an iterator over all `n`-bit values with exactly `k` bits set, in
+/// increasing order (using Gosper's hack).
+///
+/// If `k` is 0, the only subset is 0. If `k > n`, there are no subsets.
+///
+/// Panics if `n` is larger than 32.
+pub fn subsets_of_size(n: u32, k: u32) -> impl Iterator<Item = u32> {
+    assert!(n <= 32, "Subsets are limited to 32 bits");
+
+    let limit = 1u64 << n;
+    let first = if k <= n { Some((1u64 << k) - 1) } else { None };
+
+    std::iter::successors(first, move |&x| {
+        if x == 0 {
+            return None;
+        }
+
+        let lowest = x & x.wrapping_neg();
+        let ripple = x + lowest;
+        let next = (((ripple ^ x) >> 2) / lowest) | ripple;
+
+        (next < limit).then_some(next)
+    })
+    .map(|x| x as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gray_code() {
+        assert_eq!(
+            gray_code_sequence(3).collect::<Vec<_>>(),
+            vec![0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]
+        );
+
+        let sequence = gray_code_sequence(10).collect::<Vec<_>>();
+        let mut sorted = sequence.clone();
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, (0..1024).collect::<Vec<_>>());
+        assert!(sequence.windows(2).all(|w| (w[0] ^ w[1]).count_ones() == 1));
+
+        assert_eq!(gray_code_sequence(0).collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn test_subsets_of_size() {
+        assert_eq!(
+            subsets_of_size(4, 2).collect::<Vec<_>>(),
+            vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]
+        );
+
+        for n in 0..=10 {
+            for k in 0..=n {
+                let naive = (0..1u32 << n)
+                    .filter(|x| x.count_ones() == k)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(subsets_of_size(n, k).collect::<Vec<_>>(), naive);
+            }
+        }
+
+        assert_eq!(subsets_of_size(3, 4).count(), 0);
+        assert_eq!(subsets_of_size(32, 32).collect::<Vec<_>>(), vec![u32::MAX]);
+        assert_eq!(subsets_of_size(32, 1).count(), 32);
+    }
+}
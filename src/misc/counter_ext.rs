@@ -0,0 +1,351 @@
+use std::hash::Hash;
+
+use counter::Counter;
+use rand::Rng;
+
+use crate::prelude::HashMap;
+
+/// Extension methods for [`Counter`].
+///
+/// The `counter` crate covers the basics; this trait adds the helpers that
+/// keep coming up in puzzle code. Import it via the prelude.
+///
+/// Filtering is not part of this trait: `Counter` derefs to its HashMap, so
+/// `counter.retain(|item, count| ...)` already works. The Counter does not
+/// cache its total, so `total()` stays consistent afterwards.
+pub trait CounterExt<T> {
+    /// Creates a Counter from pre-aggregated `(item, count)` pairs.
+    ///
+    /// Counts of duplicate items are summed, which makes this useful for
+    /// merging histograms that were computed separately (e.g. per chunk).
+    fn from_counts(counts: impl IntoIterator<Item = (T, usize)>) -> Self;
+
+    /// Adds the given `(item, count)` pairs to the Counter.
+    fn extend_counts(&mut self, counts: impl IntoIterator<Item = (T, usize)>);
+
+    /// Treats the Counter as a weighted sample and returns the key at
+    /// cumulative frequency `q` (e.g. `0.5` for the median).
+    ///
+    /// `q` is clamped to `[0, 1]`. Returns `None` if the Counter is empty or `q`
+    /// is NaN.
+    fn quantile(&self, q: f64) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Returns the most frequent item, or `None` if the Counter is empty.
+    ///
+    /// Ties are broken in favor of the smallest item, so the result does not
+    /// depend on the iteration order of the underlying HashMap.
+    fn argmax(&self) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Returns the least frequent item, or `None` if the Counter is empty.
+    ///
+    /// Ties are broken in favor of the smallest item.
+    fn argmin(&self) -> Option<&T>
+    where
+        T: Ord;
+
+    /// Picks an item at random with a probability proportional to its count,
+    /// or returns `None` if the Counter is empty.
+    fn sample(&self, rng: &mut impl Rng) -> Option<&T>;
+
+    /// Returns `n` independent draws from the Counter (see
+    /// [`CounterExt::sample`]). Returns an empty Vec if the Counter is empty.
+    fn sample_n(&self, n: usize, rng: &mut impl Rng) -> Vec<&T>;
+
+    /// Returns the Shannon entropy of the frequency distribution in bits (i.e.
+    /// using the base-2 logarithm).
+    ///
+    /// Returns 0.0 for an empty Counter or one with a single item.
+    fn entropy(&self) -> f64;
+
+    /// Returns the Gini impurity of the frequency distribution: the probability
+    /// that two items drawn at random (with replacement) are different.
+    ///
+    /// Returns 0.0 for an empty Counter or one with a single item.
+    fn gini_impurity(&self) -> f64;
+
+    /// Returns the signed change in count of every item from `other` to
+    /// `self`, i.e. `self[item] - other[item]`.
+    ///
+    /// Unlike subtracting Counters, this does not saturate at zero. Items whose
+    /// count is the same in both Counters are omitted.
+    fn diff<'a>(&'a self, other: &'a Self) -> HashMap<&'a T, i64>;
+}
+
+impl<T: Hash + Eq> CounterExt<T> for Counter<T> {
+    fn from_counts(counts: impl IntoIterator<Item = (T, usize)>) -> Self {
+        let mut counter = Counter::new();
+        counter.extend_counts(counts);
+        counter
+    }
+
+    fn extend_counts(&mut self, counts: impl IntoIterator<Item = (T, usize)>) {
+        for (item, count) in counts {
+            if count == 0 {
+                continue;
+            }
+
+            *self.entry(item).or_insert(0) += count;
+        }
+    }
+
+    fn quantile(&self, q: f64) -> Option<&T>
+    where
+        T: Ord,
+    {
+        if q.is_nan() {
+            return None;
+        }
+
+        let total = self.total::<usize>();
+
+        if total == 0 {
+            return None;
+        }
+
+        let rank = ((q.clamp(0.0, 1.0) * total as f64).ceil() as usize).max(1);
+
+        let mut keys = self.iter().collect::<Vec<_>>();
+        keys.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        let mut cumulative = 0;
+
+        for (key, count) in keys {
+            cumulative += count;
+
+            if cumulative >= rank {
+                return Some(key);
+            }
+        }
+
+        None
+    }
+
+    fn argmax(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(item, _)| item)
+    }
+
+    fn argmin(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter()
+            .min_by(|a, b| a.1.cmp(b.1).then_with(|| a.0.cmp(b.0)))
+            .map(|(item, _)| item)
+    }
+
+    fn sample(&self, rng: &mut impl Rng) -> Option<&T> {
+        self.sample_n(1, rng).pop()
+    }
+
+    fn sample_n(&self, n: usize, rng: &mut impl Rng) -> Vec<&T> {
+        let mut total = 0;
+
+        let cumulative = self
+            .iter()
+            .filter(|(_, &count)| count > 0)
+            .map(|(item, count)| {
+                total += count;
+                (total, item)
+            })
+            .collect::<Vec<_>>();
+
+        if total == 0 {
+            return Vec::new();
+        }
+
+        (0..n)
+            .map(|_| {
+                let r = rng.gen_range(0..total);
+                let idx = cumulative.partition_point(|(cum, _)| *cum <= r);
+
+                cumulative[idx].1
+            })
+            .collect()
+    }
+
+    fn entropy(&self) -> f64 {
+        probabilities(self)
+            .filter(|&p| p > 0.0)
+            .map(|p| -p * p.log2())
+            .sum::<f64>()
+            .max(0.0)
+    }
+
+    fn gini_impurity(&self) -> f64 {
+        if self.total::<usize>() == 0 {
+            return 0.0;
+        }
+
+        (1.0 - probabilities(self).map(|p| p * p).sum::<f64>()).max(0.0)
+    }
+
+    fn diff<'a>(&'a self, other: &'a Self) -> HashMap<&'a T, i64> {
+        let mut delta: HashMap<&T, i64> = HashMap::default();
+
+        for (item, &count) in self.iter() {
+            *delta.entry(item).or_insert(0) += count as i64;
+        }
+
+        for (item, &count) in other.iter() {
+            *delta.entry(item).or_insert(0) -= count as i64;
+        }
+
+        delta.retain(|_, d| *d != 0);
+        delta
+    }
+}
+
+// The relative frequency of each item
+fn probabilities<T: Hash + Eq>(counter: &Counter<T>) -> impl Iterator<Item = f64> + '_ {
+    let total = counter.total::<usize>() as f64;
+
+    counter.values().map(move |&count| count as f64 / total)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_from_counts() {
+        let counter = Counter::from_counts([('a', 2), ('b', 3), ('a', 4)]);
+
+        assert_eq!(counter[&'a'], 6);
+        assert_eq!(counter[&'b'], 3);
+        assert_eq!(counter.len(), 2);
+        assert_eq!(counter.total::<usize>(), 9);
+    }
+
+    #[test]
+    fn test_extend_counts() {
+        let mut counter = "abbccc".chars().collect::<Counter<_>>();
+        counter.extend_counts([('a', 1), ('d', 2), ('e', 0)]);
+
+        assert_eq!(counter[&'a'], 2);
+        assert_eq!(counter[&'d'], 2);
+        assert!(!counter.contains_key(&'e'));
+        assert_eq!(counter.total::<usize>(), 9);
+    }
+
+    #[test]
+    fn test_quantile() {
+        let counter = Counter::from_counts([(1, 1), (2, 1), (3, 1)]);
+
+        assert_eq!(counter.quantile(0.0), Some(&1));
+        assert_eq!(counter.quantile(0.5), Some(&2));
+        assert_eq!(counter.quantile(1.0), Some(&3));
+        assert_eq!(counter.quantile(-1.0), Some(&1));
+        assert_eq!(counter.quantile(2.0), Some(&3));
+        assert_eq!(counter.quantile(f64::NAN), None);
+
+        let counter = Counter::from_counts([(10, 1), (20, 8), (30, 1)]);
+
+        assert_eq!(counter.quantile(0.1), Some(&10));
+        assert_eq!(counter.quantile(0.5), Some(&20));
+        assert_eq!(counter.quantile(0.9), Some(&20));
+        assert_eq!(counter.quantile(0.95), Some(&30));
+
+        let empty: Counter<i32> = Counter::new();
+        assert_eq!(empty.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_argmax_argmin() {
+        let counter = "abracadabra".chars().collect::<Counter<_>>();
+
+        assert_eq!(counter.argmax(), Some(&'a'));
+        assert_eq!(counter.argmin(), Some(&'c'));
+
+        let counter = Counter::from_counts([('x', 2), ('b', 2), ('m', 2)]);
+
+        assert_eq!(counter.argmax(), Some(&'b'));
+        assert_eq!(counter.argmin(), Some(&'b'));
+
+        let empty: Counter<char> = Counter::new();
+
+        assert_eq!(empty.argmax(), None);
+        assert_eq!(empty.argmin(), None);
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let counter = Counter::from_counts([('a', 1), ('b', 3)]);
+
+        let draws = counter.sample_n(10_000, &mut rng);
+        let b = draws.iter().filter(|&&&c| c == 'b').count();
+
+        assert_eq!(draws.len(), 10_000);
+        assert!((7300..7700).contains(&b));
+
+        assert!(counter.sample(&mut rng).is_some());
+
+        let empty: Counter<char> = Counter::new();
+
+        assert_eq!(empty.sample(&mut rng), None);
+        assert!(empty.sample_n(5, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut counter = "abracadabra".chars().collect::<Counter<_>>();
+
+        counter.retain(|_, count| *count > 1);
+
+        assert_eq!(counter.len(), 3);
+        assert!(!counter.contains_key(&'c'));
+        assert!(!counter.contains_key(&'d'));
+        assert_eq!(counter.total::<usize>(), 9);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let uniform = Counter::from_counts([('a', 2), ('b', 2), ('c', 2), ('d', 2)]);
+        assert!((uniform.entropy() - 2.0).abs() < 1e-12);
+
+        let skewed = Counter::from_counts([('a', 3), ('b', 1)]);
+        assert!((skewed.entropy() - 0.811_278_124_459_132_8).abs() < 1e-12);
+
+        assert_eq!(Counter::from_counts([('a', 5)]).entropy(), 0.0);
+        assert_eq!(Counter::<char>::new().entropy(), 0.0);
+    }
+
+    #[test]
+    fn test_gini_impurity() {
+        let uniform = Counter::from_counts([('a', 2), ('b', 2), ('c', 2), ('d', 2)]);
+        assert!((uniform.gini_impurity() - 0.75).abs() < 1e-12);
+
+        let skewed = Counter::from_counts([('a', 3), ('b', 1)]);
+        assert!((skewed.gini_impurity() - 0.375).abs() < 1e-12);
+
+        assert_eq!(Counter::from_counts([('a', 5)]).gini_impurity(), 0.0);
+        assert_eq!(Counter::<char>::new().gini_impurity(), 0.0);
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = Counter::from_counts([('a', 3), ('b', 1), ('c', 2)]);
+        let after = Counter::from_counts([('a', 1), ('c', 2), ('d', 4)]);
+
+        let delta = after.diff(&before);
+
+        assert_eq!(delta.len(), 3);
+        assert_eq!(delta[&'a'], -2);
+        assert_eq!(delta[&'b'], -1);
+        assert_eq!(delta[&'d'], 4);
+        assert!(!delta.contains_key(&'c'));
+
+        assert!(after.diff(&after).is_empty());
+    }
+}
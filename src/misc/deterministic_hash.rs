@@ -0,0 +1,79 @@
+use std::hash::BuildHasher;
+
+use ahash::{AHasher, RandomState};
+
+/// A HashMap that uses ahash with fixed seeds, so that iteration order is
+/// reproducible from run to run.
+///
+/// This is useful for debugging and snapshot tests. Use the regular `HashMap`
+/// everywhere else.
+pub type DeterministicHashMap<K, V> = std::collections::HashMap<K, V, DeterministicState>;
+
+/// A HashSet that uses ahash with fixed seeds, so that iteration order is
+/// reproducible from run to run.
+pub type DeterministicHashSet<T> = std::collections::HashSet<T, DeterministicState>;
+
+/// Creates an empty [`DeterministicHashMap`].
+pub fn det_map<K, V>() -> DeterministicHashMap<K, V> {
+    DeterministicHashMap::default()
+}
+
+/// Creates an empty [`DeterministicHashSet`].
+pub fn det_set<T>() -> DeterministicHashSet<T> {
+    DeterministicHashSet::default()
+}
+
+/// Builds ahash hashers with fixed seeds.
+///
+/// `RandomState::with_seed` still mixes in per-process random seeds when
+/// ahash's `runtime-rng` feature is enabled, so we use `with_seeds`, which
+/// does not.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeterministicState;
+
+const SEEDS: RandomState = RandomState::with_seeds(
+    0x243f_6a88_85a3_08d3,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+);
+
+impl BuildHasher for DeterministicState {
+    type Hasher = AHasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        SEEDS.build_hasher()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iteration_order_is_reproducible() {
+        let mut a = det_map();
+        let mut b = det_map();
+
+        for i in 0..100 {
+            a.insert(i, i * 2);
+        }
+
+        for i in 0..100 {
+            b.insert(i, i * 2);
+        }
+
+        assert!(a.iter().eq(b.iter()));
+        assert_eq!(
+            DeterministicState.hash_one("hello"),
+            DeterministicState.hash_one("hello")
+        );
+
+        let set: DeterministicHashSet<_> = (0..100).collect();
+        assert_eq!(set, a.keys().copied().collect());
+
+        let mut set = det_set();
+        set.insert("hello");
+        assert!(set.contains("hello"));
+    }
+}
@@ -0,0 +1,111 @@
+use num::Integer;
+
+/// Sorts and merges overlapping or adjacent closed intervals.
+///
+/// Since the intervals are interpreted as ranges of integers, intervals that
+/// touch are merged as well: `[1, 3]` and `[4, 6]` become `[1, 6]`. Intervals
+/// with `start > end` are empty and are dropped.
+pub fn merge<T: Integer + Copy>(intervals: &[(T, T)]) -> Vec<(T, T)> {
+    let mut sorted = intervals
+        .iter()
+        .filter(|(start, end)| start <= end)
+        .copied()
+        .collect::<Vec<_>>();
+
+    sorted.sort_unstable();
+
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(sorted.len());
+
+    for (start, end) in sorted {
+        if let Some(last) = merged.last_mut() {
+            if start <= last.1 || start - T::one() <= last.1 {
+                last.1 = last.1.max(end);
+                continue;
+            }
+        }
+
+        merged.push((start, end));
+    }
+
+    merged
+}
+
+/// Removes the given holes from the closed interval `a`, returning the
+/// remaining pieces in ascending order.
+pub fn subtract<T: Integer + Copy>(a: (T, T), holes: &[(T, T)]) -> Vec<(T, T)> {
+    let (start, end) = a;
+    let mut result = Vec::new();
+
+    if start > end {
+        return result;
+    }
+
+    let mut cur = start;
+
+    for (hole_start, hole_end) in merge(holes) {
+        if hole_end < cur {
+            continue;
+        }
+
+        if hole_start > end {
+            break;
+        }
+
+        if hole_start > cur {
+            result.push((cur, hole_start - T::one()));
+        }
+
+        if hole_end >= end {
+            return result;
+        }
+
+        cur = hole_end + T::one();
+    }
+
+    result.push((cur, end));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(merge(&[(6, 8), (1, 3), (2, 4)]), vec![(1, 4), (6, 8)]);
+        assert_eq!(merge(&[(1, 3), (4, 6)]), vec![(1, 6)]);
+        assert_eq!(merge(&[(1, 3), (5, 6)]), vec![(1, 3), (5, 6)]);
+        assert_eq!(merge(&[(1, 10), (2, 3), (4, 5)]), vec![(1, 10)]);
+        assert_eq!(merge(&[(3, 1), (7, 7)]), vec![(7, 7)]);
+        assert_eq!(merge::<i64>(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_merge_extremes() {
+        assert_eq!(merge(&[(0u8, 10), (11, 255), (0, 0)]), vec![(0u8, 255)]);
+
+        assert_eq!(
+            merge(&[(i64::MIN, 0), (1, i64::MAX)]),
+            vec![(i64::MIN, i64::MAX)]
+        );
+
+        assert_eq!(
+            merge(&[(i64::MIN, i64::MIN), (i64::MAX, i64::MAX)]),
+            vec![(i64::MIN, i64::MIN), (i64::MAX, i64::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_subtract() {
+        assert_eq!(subtract((1, 10), &[]), vec![(1, 10)]);
+        assert_eq!(subtract((1, 10), &[(3, 4)]), vec![(1, 2), (5, 10)]);
+        assert_eq!(subtract((1, 10), &[(0, 2), (9, 20)]), vec![(3, 8)]);
+        assert_eq!(
+            subtract((1, 10), &[(6, 7), (3, 4)]),
+            vec![(1, 2), (5, 5), (8, 10)]
+        );
+        assert_eq!(subtract((1, 10), &[(1, 10)]), vec![]);
+        assert_eq!(subtract((1, 10), &[(11, 12), (-5, 0)]), vec![(1, 10)]);
+        assert_eq!(subtract((0u8, 255), &[(1, 254)]), vec![(0, 0), (255, 255)]);
+    }
+}
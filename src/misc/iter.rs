@@ -0,0 +1,55 @@
+/// Groups runs of equal consecutive items, yielding each item together with
+/// the length of its run.
+///
+/// This is a lazy version of [`rle::encode`](super::rle::encode) that works on
+/// any iterator, so it can be used on streaming input. Only runs of
+/// *consecutive* equal items are merged; sort the input first if you want to
+/// count all occurrences.
+///
+/// # Example
+///
+/// ```
+/// use utility_belt::prelude::*;
+///
+/// let digits = parse_uints("1 1 2 2 2 1 3");
+/// let runs = group_consecutive(digits.into_iter()).collect::<Vec<_>>();
+///
+/// assert_eq!(runs, vec![(1, 2), (2, 3), (1, 1), (3, 1)]);
+/// ```
+pub fn group_consecutive<T: PartialEq, I: Iterator<Item = T>>(
+    iter: I,
+) -> impl Iterator<Item = (T, usize)> {
+    let mut iter = iter.peekable();
+
+    std::iter::from_fn(move || {
+        let first = iter.next()?;
+        let mut count = 1;
+
+        while iter.next_if_eq(&first).is_some() {
+            count += 1;
+        }
+
+        Some((first, count))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_consecutive() {
+        let runs = group_consecutive("aaabccddddd".chars()).collect::<Vec<_>>();
+        assert_eq!(runs, vec![('a', 3), ('b', 1), ('c', 2), ('d', 5)]);
+
+        assert_eq!(group_consecutive(std::iter::empty::<u8>()).count(), 0);
+    }
+
+    #[test]
+    fn test_group_consecutive_is_lazy() {
+        let mut runs = group_consecutive([1, 1, 2].into_iter().chain(std::iter::repeat(3)));
+
+        assert_eq!(runs.next(), Some((1, 2)));
+        assert_eq!(runs.next(), Some((2, 1)));
+    }
+}
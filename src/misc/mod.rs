@@ -1,4 +1,26 @@
+pub mod bits;
+pub mod counter_ext;
+pub mod deterministic_hash;
+pub mod intervals;
+pub mod iter;
+pub mod rle;
+pub mod sample;
+pub mod shuffle;
+pub mod sliding;
+pub mod strings;
 pub mod union_find;
+
+pub use bits::*;
+pub use counter_ext::*;
+pub use deterministic_hash::*;
+// `intervals` and `rle` are not glob-exported: `intervals::merge` would clash
+// with `itertools::merge` in the prelude, and `rle::encode`/`rle::decode` are
+// too generic to be useful without the module name.
+pub use iter::*;
+pub use sample::*;
+pub use shuffle::*;
+pub use sliding::*;
+pub use strings::*;
 pub use union_find::*;
 
 use std::hash::Hash;
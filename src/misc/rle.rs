@@ -0,0 +1,59 @@
+/// Run-length encodes the given items into `(item, run length)` pairs.
+///
+/// Adjacent runs always contain different items, and every run length is
+/// at least 1.
+pub fn encode<T: PartialEq + Clone>(items: &[T]) -> Vec<(T, usize)> {
+    encode_iter(items)
+}
+
+/// Expands `(item, run length)` pairs back into the original sequence.
+///
+/// This is the inverse of [`encode`]. Runs of length 0 are skipped.
+pub fn decode<T: Clone>(runs: &[(T, usize)]) -> Vec<T> {
+    runs.iter()
+        .flat_map(|(item, len)| std::iter::repeat_n(item.clone(), *len))
+        .collect()
+}
+
+// Like `encode`, but for data that is not available as a contiguous slice,
+// such as a grid row.
+pub(crate) fn encode_iter<'a, T: PartialEq + Clone + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+) -> Vec<(T, usize)> {
+    let mut runs: Vec<(T, usize)> = Vec::new();
+
+    for item in items {
+        match runs.last_mut() {
+            Some((last, len)) if last == item => *len += 1,
+            _ => runs.push((item.clone(), 1)),
+        }
+    }
+
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let chars = "aaabccddddd".chars().collect::<Vec<_>>();
+
+        assert_eq!(encode(&chars), vec![('a', 3), ('b', 1), ('c', 2), ('d', 5)]);
+
+        assert_eq!(encode::<i32>(&[]), vec![]);
+        assert_eq!(encode(&[7]), vec![(7, 1)]);
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            decode(&[('x', 2), ('y', 0), ('z', 3)]),
+            vec!['x', 'x', 'z', 'z', 'z']
+        );
+
+        let items = vec![1, 1, 2, 3, 3, 3, 1];
+        assert_eq!(decode(&encode(&items)), items);
+    }
+}
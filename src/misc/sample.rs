@@ -0,0 +1,162 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use rand::Rng;
+
+/// Reservoir sampling
+///
+/// Picks `k` items uniformly at random from a stream of unknown length in a
+/// single pass (Algorithm R). If the stream contains `k` items or fewer, all of
+/// them are returned.
+///
+/// The order of the returned items is unspecified.
+///
+/// See [Wikipedia](https://en.wikipedia.org/wiki/Reservoir_sampling) for more
+/// information.
+///
+pub fn reservoir<T>(items: impl Iterator<Item = T>, k: usize, rng: &mut impl Rng) -> Vec<T> {
+    let mut reservoir = Vec::with_capacity(k);
+
+    if k == 0 {
+        return reservoir;
+    }
+
+    for (i, item) in items.enumerate() {
+        if i < k {
+            reservoir.push(item);
+            continue;
+        }
+
+        let j = rng.gen_range(0..=i);
+
+        if j < k {
+            reservoir[j] = item;
+        }
+    }
+
+    reservoir
+}
+
+/// Weighted reservoir sampling
+///
+/// Picks `k` items without replacement from a stream of `(item, weight)` pairs,
+/// where the probability of an item being picked is proportional to its weight
+/// (Algorithm A-Res by Efraimidis and Spirakis).
+///
+/// Items with a weight that is not strictly positive are never picked.
+///
+pub fn reservoir_weighted<T>(
+    items: impl Iterator<Item = (T, f64)>,
+    k: usize,
+    rng: &mut impl Rng,
+) -> Vec<T> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+
+    if k == 0 {
+        return Vec::new();
+    }
+
+    for (item, weight) in items {
+        if weight <= 0.0 {
+            continue;
+        }
+
+        let key = rng.gen::<f64>().powf(1.0 / weight);
+
+        if heap.len() < k {
+            heap.push(Keyed { key, item });
+        } else if heap.peek().is_some_and(|min: &Keyed<T>| key > min.key) {
+            heap.pop();
+            heap.push(Keyed { key, item });
+        }
+    }
+
+    heap.into_iter().map(|keyed| keyed.item).collect()
+}
+
+// Heap entry for `reservoir_weighted`. The ordering is reversed, so that the
+// max-heap keeps the smallest key on top.
+struct Keyed<T> {
+    key: f64,
+    item: T,
+}
+
+impl<T> PartialEq for Keyed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<T> Eq for Keyed<T> {}
+
+impl<T> PartialOrd for Keyed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Keyed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.total_cmp(&self.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_reservoir() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let mut sample = reservoir(0..100, 5, &mut rng);
+        sample.sort();
+        sample.dedup();
+
+        assert_eq!(sample.len(), 5);
+        assert!(sample.iter().all(|x| (0..100).contains(x)));
+
+        assert_eq!(reservoir(0..3, 5, &mut rng).len(), 3);
+        assert!(reservoir(0..3, 0, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_reservoir_is_uniform() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut counts = [0; 10];
+
+        for _ in 0..10_000 {
+            for x in reservoir(0..10, 2, &mut rng) {
+                counts[x] += 1;
+            }
+        }
+
+        // Each item should be picked roughly 2000 times
+        assert!(counts.iter().all(|&c| (1800..2200).contains(&c)));
+    }
+
+    #[test]
+    fn test_reservoir_weighted() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut counts = [0; 3];
+
+        for _ in 0..10_000 {
+            let items = [(0, 1.0), (1, 9.0), (2, 0.0)].into_iter();
+
+            for x in reservoir_weighted(items, 1, &mut rng) {
+                counts[x] += 1;
+            }
+        }
+
+        assert!((800..1200).contains(&counts[0]));
+        assert!((8800..9200).contains(&counts[1]));
+        assert_eq!(counts[2], 0);
+
+        let mut sample = reservoir_weighted((0..10).map(|x| (x, 1.0)), 4, &mut rng);
+        sample.sort();
+        sample.dedup();
+
+        assert_eq!(sample.len(), 4);
+    }
+}
@@ -0,0 +1,59 @@
+use rand::Rng;
+
+/// Shuffles the items in place using the Fisher-Yates algorithm.
+///
+/// Every permutation is equally likely. Passing a seeded rng makes the result
+/// reproducible.
+pub fn shuffle_in_place<T>(items: &mut [T], rng: &mut impl Rng) {
+    for i in (1..items.len()).rev() {
+        let j = rng.gen_range(0..=i);
+        items.swap(i, j);
+    }
+}
+
+/// Returns a shuffled copy of the items (see [`shuffle_in_place`]).
+pub fn shuffled<T: Clone>(items: &[T], rng: &mut impl Rng) -> Vec<T> {
+    let mut result = items.to_vec();
+    shuffle_in_place(&mut result, rng);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_shuffle_is_reproducible() {
+        let items = (0..10).collect::<Vec<_>>();
+
+        let a = shuffled(&items, &mut StdRng::seed_from_u64(42));
+        let b = shuffled(&items, &mut StdRng::seed_from_u64(42));
+
+        assert_eq!(a, b);
+        assert_eq!(a, vec![7, 4, 1, 2, 8, 6, 9, 3, 0, 5]);
+
+        let mut sorted = a.clone();
+        sorted.sort();
+        assert_eq!(sorted, items);
+    }
+
+    #[test]
+    fn test_shuffle_is_uniform() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut counts = [[0; 3]; 3];
+
+        for _ in 0..9000 {
+            let mut items = [0, 1, 2];
+            shuffle_in_place(&mut items, &mut rng);
+
+            for (pos, &item) in items.iter().enumerate() {
+                counts[item][pos] += 1;
+            }
+        }
+
+        // Each item should end up in each position roughly 3000 times
+        assert!(counts.iter().flatten().all(|&c| (2800..3200).contains(&c)));
+    }
+}
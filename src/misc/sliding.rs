@@ -0,0 +1,94 @@
+use std::collections::VecDeque;
+
+/// Returns the maximum of every window of `k` consecutive items, in O(n) time
+/// using a monotonic deque.
+///
+/// The result has `items.len() - k + 1` elements. If `k` is 0 or larger than
+/// the number of items, there are no windows and the result is empty.
+pub fn window_max<T: Ord + Clone>(items: &[T], k: usize) -> Vec<T> {
+    window_extremum(items, k, |new, old| new >= old)
+}
+
+/// Returns the minimum of every window of `k` consecutive items (see
+/// [`window_max`]).
+pub fn window_min<T: Ord + Clone>(items: &[T], k: usize) -> Vec<T> {
+    window_extremum(items, k, |new, old| new <= old)
+}
+
+// `dominates(new, old)` returns whether `old` can never be the answer for a
+// window that contains `new`.
+fn window_extremum<T: Clone>(items: &[T], k: usize, dominates: impl Fn(&T, &T) -> bool) -> Vec<T> {
+    if k == 0 || k > items.len() {
+        return Vec::new();
+    }
+
+    // Indices of the candidates, whose values are monotonic from front to back
+    let mut deque: VecDeque<usize> = VecDeque::with_capacity(k);
+    let mut result = Vec::with_capacity(items.len() - k + 1);
+
+    for (i, item) in items.iter().enumerate() {
+        while deque.back().is_some_and(|&j| dominates(item, &items[j])) {
+            deque.pop_back();
+        }
+
+        deque.push_back(i);
+
+        if deque[0] + k <= i {
+            deque.pop_front();
+        }
+
+        if i + 1 >= k {
+            result.push(items[deque[0]].clone());
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_max() {
+        assert_eq!(
+            window_max(&[1, 3, -1, -3, 5, 3, 6, 7], 3),
+            vec![3, 3, 5, 5, 6, 7]
+        );
+        assert_eq!(
+            window_min(&[1, 3, -1, -3, 5, 3, 6, 7], 3),
+            vec![-1, -3, -3, -3, 3, 3]
+        );
+    }
+
+    #[test]
+    fn test_window_edge_cases() {
+        assert!(window_max(&[1, 2, 3], 0).is_empty());
+        assert!(window_max(&[1, 2, 3], 4).is_empty());
+        assert!(window_min::<i32>(&[], 1).is_empty());
+        assert_eq!(window_max(&[1, 2, 3], 3), vec![3]);
+        assert_eq!(window_min(&[1, 2, 3], 1), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_window_matches_naive() {
+        let items = (0..200u64)
+            .map(|i| (i * 7919 + 13) % 101)
+            .collect::<Vec<_>>();
+
+        for k in 1..=20 {
+            let naive_max = items
+                .windows(k)
+                .map(|w| *w.iter().max().unwrap())
+                .collect::<Vec<_>>();
+
+            let naive_min = items
+                .windows(k)
+                .map(|w| *w.iter().min().unwrap())
+                .collect::<Vec<_>>();
+
+            assert_eq!(window_max(&items, k), naive_max);
+            assert_eq!(window_min(&items, k), naive_min);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::mem::swap;
+
+/// Returns the length of the longest common subsequence of `a` and `b`.
+///
+/// Works on any slice of comparable items, so it can be used to compare
+/// strings (as `&[u8]` or `&[char]`), token lists or grid rows alike. Runs in
+/// O(nm) time and O(min(n, m)) memory.
+pub fn lcs_len<T: Eq>(a: &[T], b: &[T]) -> usize {
+    // Keep the DP row as short as possible
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+
+    let mut prev = vec![0; b.len() + 1];
+    let mut cur = vec![0; b.len() + 1];
+
+    for x in a {
+        for (j, y) in b.iter().enumerate() {
+            cur[j + 1] = if x == y {
+                prev[j] + 1
+            } else {
+                prev[j + 1].max(cur[j])
+            };
+        }
+
+        swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
+/// Returns the Levenshtein distance between `a` and `b`: the minimum number
+/// of single-item insertions, deletions and substitutions needed to turn `a`
+/// into `b`.
+///
+/// Runs in O(nm) time and O(min(n, m)) memory.
+pub fn edit_distance<T: Eq>(a: &[T], b: &[T]) -> usize {
+    // The distance is symmetric, so we can keep the DP row as short as possible
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut cur = vec![0; b.len() + 1];
+
+    for (i, x) in a.iter().enumerate() {
+        cur[0] = i + 1;
+
+        for (j, y) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(x != y);
+            let deletion = prev[j + 1] + 1;
+            let insertion = cur[j] + 1;
+
+            cur[j + 1] = substitution.min(deletion).min(insertion);
+        }
+
+        swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("", "", 0)]
+    #[case("abc", "", 0)]
+    #[case("ABCBDAB", "BDCABA", 4)]
+    #[case("AGGTAB", "GXTXAYB", 4)]
+    #[case("abc", "abc", 3)]
+    #[case("abc", "def", 0)]
+    fn test_lcs_len(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+        assert_eq!(lcs_len(a.as_bytes(), b.as_bytes()), expected);
+        assert_eq!(lcs_len(b.as_bytes(), a.as_bytes()), expected);
+    }
+
+    #[rstest]
+    #[case("", "", 0)]
+    #[case("abc", "", 3)]
+    #[case("kitten", "sitting", 3)]
+    #[case("flaw", "lawn", 2)]
+    #[case("intention", "execution", 5)]
+    #[case("same", "same", 0)]
+    fn test_edit_distance(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+        assert_eq!(edit_distance(a.as_bytes(), b.as_bytes()), expected);
+        assert_eq!(edit_distance(b.as_bytes(), a.as_bytes()), expected);
+    }
+
+    #[test]
+    fn test_generic_items() {
+        let a = ["move", "left", "jump", "right"];
+        let b = ["move", "jump", "right", "right"];
+
+        assert_eq!(lcs_len(&a, &b), 3);
+        assert_eq!(edit_distance(&a, &b), 2);
+    }
+}
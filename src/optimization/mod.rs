@@ -1,2 +1,5 @@
 mod branch_and_bound;
+mod tsp;
+
 pub use branch_and_bound::branch_and_bound;
+pub use tsp::held_karp;
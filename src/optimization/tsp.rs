@@ -0,0 +1,216 @@
+/// Held-Karp algorithm for the traveling salesman problem
+///
+/// Finds the cheapest order in which to visit every node of a complete graph,
+/// given as a distance matrix where `distances[i][j]` is the cost of moving
+/// from node `i` to node `j`. The tour starts at `start` and, if
+/// `return_to_start` is true, ends with a move back to `start`. The matrix does
+/// not need to be symmetric.
+///
+/// Returns the cost of the tour and the order in which the nodes are visited,
+/// beginning with `start`. The final move back to `start` is included in the
+/// cost, but not repeated in the order. Use `u64::MAX` for missing edges; if
+/// no tour exists, `None` is returned.
+///
+/// This uses dynamic programming over subsets, which takes O(n²·2ⁿ) time and
+/// O(n·2ⁿ) memory. Each table entry takes 9 bytes, so 20 nodes need about
+/// 90 MB and 24 nodes, the maximum, already need about 1.7 GB.
+///
+/// # Panics
+///
+/// * If `start` is not a valid node index.
+/// * If there are more than 24 nodes.
+pub fn held_karp(
+    distances: &[Vec<u64>],
+    start: usize,
+    return_to_start: bool,
+) -> Option<(u64, Vec<usize>)> {
+    let n = distances.len();
+
+    assert!(start < n, "Start node {start} does not exist");
+    assert!(
+        n <= 24,
+        "Held-Karp is limited to 24 nodes, which already need about 1.7 GB of memory"
+    );
+
+    // The subsets only contain the nodes other than the start, so that the
+    // tables are half as large.
+    let others = (0..n).filter(|&i| i != start).collect::<Vec<_>>();
+    let m = others.len();
+
+    if m == 0 {
+        let cost = if return_to_start {
+            distances[start][start]
+        } else {
+            0
+        };
+
+        if cost == u64::MAX {
+            return None;
+        }
+
+        return Some((cost, vec![start]));
+    }
+
+    let dist = |from: usize, to: usize| distances[others[from]][others[to]];
+    let full: u32 = ((1u64 << m) - 1) as u32;
+
+    // cost[mask * m + j]: the cheapest path that starts at `start`, visits the
+    // nodes in `mask` and ends at node `j`. `parent` stores the node before `j`.
+    let mut cost = vec![u64::MAX; (full as usize + 1) * m];
+    let mut parent = vec![u8::MAX; (full as usize + 1) * m];
+
+    for (j, &node) in others.iter().enumerate() {
+        cost[(1 << j) * m + j] = distances[start][node];
+    }
+
+    for mask in 1..=full {
+        for j in (0..m).filter(|&j| mask & (1 << j) != 0) {
+            let cur = cost[mask as usize * m + j];
+
+            if cur == u64::MAX {
+                continue;
+            }
+
+            for k in (0..m).filter(|&k| mask & (1 << k) == 0) {
+                let next = (mask | (1 << k)) as usize * m + k;
+                let next_cost = cur.saturating_add(dist(j, k));
+
+                if next_cost < cost[next] {
+                    cost[next] = next_cost;
+                    parent[next] = j as u8;
+                }
+            }
+        }
+    }
+
+    let (best_cost, mut last) = (0..m)
+        .map(|j| {
+            let back = if return_to_start {
+                distances[others[j]][start]
+            } else {
+                0
+            };
+            (cost[full as usize * m + j].saturating_add(back), j)
+        })
+        .min()
+        .unwrap();
+
+    if best_cost == u64::MAX {
+        return None;
+    }
+
+    // Walk the parent pointers back to the start
+    let mut order = Vec::with_capacity(n);
+    let mut mask = full;
+
+    loop {
+        order.push(others[last]);
+
+        let prev = parent[mask as usize * m + last];
+        mask &= !(1 << last);
+
+        if prev == u8::MAX {
+            break;
+        }
+
+        last = prev as usize;
+    }
+
+    order.push(start);
+    order.reverse();
+
+    Some((best_cost, order))
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn tour_cost(distances: &[Vec<u64>], order: &[usize], return_to_start: bool) -> u64 {
+        let mut cost = order.windows(2).map(|w| distances[w[0]][w[1]]).sum::<u64>();
+
+        if return_to_start {
+            cost += distances[*order.last().unwrap()][order[0]];
+        }
+
+        cost
+    }
+
+    #[test]
+    fn test_held_karp_aoc_2016_day_24() {
+        let distances = vec![
+            vec![0, 2, 8, 10, 2],
+            vec![2, 0, 6, 8, 4],
+            vec![8, 6, 0, 2, 10],
+            vec![10, 8, 2, 0, 8],
+            vec![2, 4, 10, 8, 0],
+        ];
+
+        let (cost, order) = held_karp(&distances, 0, false).unwrap();
+        assert_eq!(cost, 14);
+        assert_eq!(order, vec![0, 4, 1, 2, 3]);
+
+        let (cost, order) = held_karp(&distances, 0, true).unwrap();
+        assert_eq!(cost, 20);
+        assert_eq!(tour_cost(&distances, &order, true), 20);
+    }
+
+    #[test]
+    fn test_held_karp_matches_brute_force() {
+        // An asymmetric matrix with pseudo-random entries
+        let n = 7;
+        let distances = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| ((i * 37 + j * 91 + i * j * 13) % 50) as u64 + 1)
+                    .collect()
+            })
+            .collect::<Vec<Vec<u64>>>();
+
+        for start in 0..n {
+            for return_to_start in [false, true] {
+                let brute_force = (0..n)
+                    .filter(|&i| i != start)
+                    .permutations(n - 1)
+                    .map(|rest| {
+                        let order = std::iter::once(start).chain(rest).collect::<Vec<_>>();
+                        tour_cost(&distances, &order, return_to_start)
+                    })
+                    .min()
+                    .unwrap();
+
+                let (cost, order) = held_karp(&distances, start, return_to_start).unwrap();
+
+                assert_eq!(cost, brute_force);
+                assert_eq!(order[0], start);
+                assert_eq!(
+                    order.iter().sorted().copied().collect::<Vec<_>>(),
+                    (0..n).collect::<Vec<_>>()
+                );
+                assert_eq!(tour_cost(&distances, &order, return_to_start), cost);
+            }
+        }
+    }
+
+    #[test]
+    fn test_held_karp_edge_cases() {
+        assert_eq!(held_karp(&[vec![0]], 0, true), Some((0, vec![0])));
+        assert_eq!(held_karp(&[vec![u64::MAX]], 0, true), None);
+
+        let unreachable = vec![vec![0, u64::MAX], vec![u64::MAX, 0]];
+        assert_eq!(held_karp(&unreachable, 0, false), None);
+
+        // A path exists, but there is no way back to the start
+        let one_way = vec![vec![0, 5], vec![u64::MAX, 0]];
+        assert_eq!(held_karp(&one_way, 0, false), Some((5, vec![0, 1])));
+        assert_eq!(held_karp(&one_way, 0, true), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Held-Karp is limited to 24 nodes")]
+    fn test_held_karp_too_many_nodes() {
+        let _ = held_karp(&vec![vec![1; 25]; 25], 0, true);
+    }
+}
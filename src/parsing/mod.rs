@@ -21,6 +21,95 @@ pub fn parse_capitals(input: &str) -> Vec<String> {
     parse_values(input, |c| !c.is_ascii_uppercase())
 }
 
+/// Parses whitespace-separated columns of values, e.g. two lists of numbers
+/// side by side, and returns one Vec per column.
+///
+/// Blank lines are skipped. Every other line must have the same number of
+/// values; ragged rows are not padded.
+///
+/// Panics if the rows have different lengths or a value fails to parse.
+pub fn parse_columns<T: FromStr>(input: &str) -> Vec<Vec<T>> {
+    let mut columns: Vec<Vec<T>> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let row = line
+            .split_whitespace()
+            .map(|token| {
+                token
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Could not parse `{token}` on line {}", i + 1))
+            })
+            .collect::<Vec<T>>();
+
+        if row.is_empty() {
+            continue;
+        }
+
+        if columns.is_empty() {
+            columns.resize_with(row.len(), Vec::new);
+        }
+
+        assert!(
+            row.len() == columns.len(),
+            "Line {} has {} columns instead of {}",
+            i + 1,
+            row.len(),
+            columns.len()
+        );
+
+        for (column, value) in columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+    }
+
+    columns
+}
+
+/// Splits the input into records separated by one or more blank lines.
+///
+/// Lines containing only whitespace count as blank, and both `\n` and `\r\n`
+/// line endings are supported. The records are returned as slices of the
+/// input without their trailing line ending; empty records are skipped.
+pub fn records(input: &str) -> Vec<&str> {
+    split_records(input, |line| line.trim().is_empty())
+}
+
+/// Like `records`, but splits on lines that are equal to `sep` (ignoring a
+/// trailing `\r`).
+pub fn records_by<'a>(input: &'a str, sep: &str) -> Vec<&'a str> {
+    split_records(input, |line| line == sep)
+}
+
+fn split_records(input: &str, is_separator: impl Fn(&str) -> bool) -> Vec<&str> {
+    let mut records = Vec::new();
+
+    // Byte range of the current record
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches('\n').trim_end_matches('\r');
+
+        if is_separator(content) {
+            if let Some(s) = start.take() {
+                records.push(&input[s..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(s) = start {
+        records.push(&input[s..end]);
+    }
+
+    records
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,4 +160,43 @@ mod tests {
                 .collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_records() {
+        assert_eq!(
+            records("1000\n2000\n\n3000\n\n\n\n4000\n5000\n\n"),
+            vec!["1000\n2000", "3000", "4000\n5000"]
+        );
+
+        assert_eq!(records("\r\na\r\nb\r\n  \r\nc\r\n"), vec!["a\r\nb", "c"]);
+        assert_eq!(records("single"), vec!["single"]);
+        assert!(records("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_records_by() {
+        assert_eq!(
+            records_by("a\nb\n---\nc\n---\r\n---\nd", "---"),
+            vec!["a\nb", "c", "d"]
+        );
+
+        assert_eq!(records_by("a\n\nb", "---"), vec!["a\n\nb"]);
+    }
+
+    #[test]
+    fn test_parse_columns() {
+        let columns: Vec<Vec<u32>> = parse_columns("3   4\n4   3\n\n2   5\n");
+        assert_eq!(columns, vec![vec![3, 4, 2], vec![4, 3, 5]]);
+
+        let columns: Vec<Vec<String>> = parse_columns("a b c\r\nd e f");
+        assert_eq!(columns[2], vec!["c", "f"]);
+
+        assert!(parse_columns::<u32>("").is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Line 2 has 1 columns instead of 2")]
+    fn test_parse_columns_ragged() {
+        parse_columns::<u32>("1 2\n3");
+    }
 }
@@ -0,0 +1,250 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+use num::Zero;
+
+use crate::prelude::HashMap;
+
+/// A* search with a cost ceiling
+///
+/// Searches for the cheapest path from `start` to a node for which `goal`
+/// returns true, but prunes every node whose estimated total cost (`g + h`)
+/// exceeds `max_cost`. This turns A* into a cost-limited feasibility check:
+/// "is the goal reachable with a cost of at most `max_cost`?"
+///
+/// Returns the path (including `start` and the goal node) and its cost, or
+/// `None` if no goal can be reached within the budget.
+///
+/// # Arguments
+///
+/// * `start` - The starting node
+/// * `successors` - Returns the successors of a node together with the cost of moving there.
+/// * `goal` - Returns whether the given node is a goal node.
+/// * `heuristic` - An admissible estimate of the remaining cost to reach a goal.
+/// * `max_cost` - The maximum cost a path may have.
+///
+pub fn astar_bounded<N, C, FN, IN, FG, FH>(
+    start: &N,
+    mut successors: FN,
+    goal: FG,
+    heuristic: FH,
+    max_cost: C,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+    FH: FnMut(&N) -> C,
+{
+    astar_core(
+        start,
+        |node, _| successors(node),
+        goal,
+        heuristic,
+        Some(max_cost),
+    )
+}
+
+/// A* search with cost-aware successors
+///
+/// Like a regular A* search, but `successors` is also given the cost of the
+/// cheapest known path to the node being expanded. This allows edge costs and
+/// passability that depend on the time elapsed so far (e.g. moving obstacles).
+///
+/// Nodes are still deduplicated by value, so if two paths that reach the same
+/// node at different costs can have different futures, the relevant part of
+/// the cost (e.g. the time modulo the period of the obstacles) has to be part
+/// of the node.
+///
+/// Returns the path (including `start` and the goal node) and its cost, or
+/// `None` if no goal can be reached.
+///
+/// # Arguments
+///
+/// * `start` - The starting node
+/// * `successors` - Returns the successors of a node, given the cost of reaching it, together with the cost of moving there.
+/// * `goal` - Returns whether the given node is a goal node.
+/// * `heuristic` - An admissible estimate of the remaining cost to reach a goal.
+///
+pub fn astar_timed<N, C, FN, IN, FG, FH>(
+    start: &N,
+    successors: FN,
+    goal: FG,
+    heuristic: FH,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N, &C) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+    FH: FnMut(&N) -> C,
+{
+    astar_core(start, successors, goal, heuristic, None)
+}
+
+// The search behind `astar_bounded` and `astar_timed`. Nodes whose estimated
+// total cost exceeds `max_cost` are pruned.
+fn astar_core<N, C, FN, IN, FG, FH>(
+    start: &N,
+    mut successors: FN,
+    mut goal: FG,
+    mut heuristic: FH,
+    max_cost: Option<C>,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N, &C) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+    FH: FnMut(&N) -> C,
+{
+    let over_budget = |estimate: C| max_cost.is_some_and(|max| estimate > max);
+    let estimate = heuristic(start);
+
+    if over_budget(estimate) {
+        return None;
+    }
+
+    // All nodes we have generated, together with the index of their parent
+    let mut nodes: Vec<(N, usize)> = vec![(start.clone(), usize::MAX)];
+
+    // The best known cost for each node and the index of the entry in `nodes`
+    // that achieves it
+    let mut best: HashMap<N, (C, usize)> = HashMap::default();
+    best.insert(start.clone(), (C::zero(), 0));
+
+    let mut open = BinaryHeap::new();
+    open.push(Reverse((estimate, C::zero(), 0)));
+
+    while let Some(Reverse((_, cost, index))) = open.pop() {
+        let node = nodes[index].0.clone();
+
+        if best[&node].1 != index {
+            // We have found a cheaper way to this node in the meantime
+            continue;
+        }
+
+        if goal(&node) {
+            return Some((reconstruct_path(&nodes, index), cost));
+        }
+
+        for (next, move_cost) in successors(&node, &cost) {
+            let next_cost = cost + move_cost;
+            let estimate = next_cost + heuristic(&next);
+
+            if over_budget(estimate) {
+                continue;
+            }
+
+            if let Some((known_cost, _)) = best.get(&next) {
+                if *known_cost <= next_cost {
+                    continue;
+                }
+            }
+
+            nodes.push((next.clone(), index));
+            best.insert(next, (next_cost, nodes.len() - 1));
+            open.push(Reverse((estimate, next_cost, nodes.len() - 1)));
+        }
+    }
+
+    None
+}
+
+fn reconstruct_path<N: Clone>(nodes: &[(N, usize)], mut index: usize) -> Vec<N> {
+    let mut path = Vec::new();
+
+    while index != usize::MAX {
+        path.push(nodes[index].0.clone());
+        index = nodes[index].1;
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A line of nodes 0..=10, with a costly shortcut from 0 to 10
+    fn successors(n: &i32) -> Vec<(i32, u32)> {
+        let mut result = vec![];
+
+        if *n < 10 {
+            result.push((n + 1, 1));
+        }
+
+        if *n == 0 {
+            result.push((10, 20));
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_astar_bounded() {
+        let result = astar_bounded(&0, successors, |n| *n == 10, |n| (10 - n) as u32, 100);
+
+        assert_eq!(result, Some(((0..=10).collect(), 10)));
+    }
+
+    #[test]
+    fn test_astar_bounded_exact_budget() {
+        let result = astar_bounded(&0, successors, |n| *n == 10, |n| (10 - n) as u32, 10);
+
+        assert_eq!(result.map(|(_, cost)| cost), Some(10));
+    }
+
+    #[test]
+    fn test_astar_bounded_over_budget() {
+        let result = astar_bounded(&0, successors, |n| *n == 10, |_| 0, 9);
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_astar_bounded_unreachable() {
+        let result = astar_bounded(&0, successors, |n| *n == 11, |_| 0, 1000);
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_astar_timed() {
+        // Walk along a line from 0 to 5; position 3 is blocked at odd times,
+        // so the walker has to wait (a move to the same node) once.
+        let successors = |&(pos, phase): &(i32, u32), &time: &u32| {
+            assert_eq!(phase, time % 2);
+
+            let next_phase = (time + 1) % 2;
+
+            [pos, pos + 1]
+                .into_iter()
+                .filter(|&next| next <= 5 && !(next == 3 && next_phase == 1))
+                .map(|next| ((next, next_phase), 1))
+                .collect::<Vec<_>>()
+        };
+
+        let (path, cost) = astar_timed(
+            &(0, 0),
+            successors,
+            |&(pos, _)| pos == 5,
+            |&(pos, _)| (5 - pos) as u32,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 6);
+        assert_eq!(path.len(), 7);
+        assert!(path
+            .iter()
+            .enumerate()
+            .all(|(t, &(pos, _))| pos != 3 || t % 2 == 0));
+
+        let unreachable = astar_timed(&(0, 0), successors, |&(pos, _)| pos == 6, |_| 0);
+        assert_eq!(unreachable, None);
+    }
+}
@@ -28,6 +28,30 @@ pub fn bisect<T: Num + Copy + PartialOrd>(lo: T, hi: T, pred: impl Fn(&T) -> boo
     }
 }
 
+/// Binary search over any type with a custom midpoint function
+///
+/// Like [`bisect`], this returns the last value for which `pred` is `false`,
+/// but it works for any `T`, because the midpoint between two values is
+/// computed by `midpoint` instead of by arithmetic. This makes it the
+/// counterpart of `slice::partition_point` for arbitrary search spaces.
+///
+/// This is a thin wrapper around [`bsearch`], which returns both sides of the
+/// boundary, and the same rules apply: `midpoint` returns `None` once there is
+/// no value left between `lo` and `hi`, and the result is `None` if `pred(lo)`
+/// is true or `pred(hi)` is false.
+///
+pub fn partition_point<T, F>(
+    lo: T,
+    hi: T,
+    midpoint: impl Fn(&T, &T) -> Option<T>,
+    pred: F,
+) -> Option<T>
+where
+    F: Fn(&T) -> bool,
+{
+    bsearch(midpoint, pred, lo, hi).map(|(left, _right)| left)
+}
+
 /// A general bisection search.
 ///
 /// It searches the interval between `lo` and `hi` for the two values between
@@ -95,4 +119,39 @@ mod tests {
         assert_eq!(bisect(0, v.len() - 1, |&i| v[i] >= 5).unwrap(), 3);
         assert_eq!(bisect(0, v.len() - 1, |&i| v[i] >= 6), None); // No switch from false to true -> None
     }
+
+    #[test]
+    fn partition_point_test() {
+        // Search over (x, y) pairs along a diagonal
+        let midpoint = |lo: &(i32, i32), hi: &(i32, i32)| {
+            if hi.0 - lo.0 > 1 {
+                let mid = (lo.0 + hi.0) / 2;
+                Some((mid, mid * 2))
+            } else {
+                None
+            }
+        };
+
+        let result = partition_point((0, 0), (100, 200), midpoint, |p| p.0 + p.1 >= 100);
+        assert_eq!(result, Some((33, 66)));
+
+        assert_eq!(
+            partition_point((0, 0), (100, 200), midpoint, |_| true),
+            None
+        );
+        assert_eq!(
+            partition_point((0, 0), (100, 200), midpoint, |_| false),
+            None
+        );
+
+        // Agrees with `bisect` on integers
+        let int_midpoint = |lo: &u32, hi: &u32| (lo + 1 < *hi).then(|| lo + (hi - lo) / 2);
+
+        for target in 1..50 {
+            assert_eq!(
+                partition_point(0, 50, int_midpoint, |&x| x >= target),
+                bisect(0, 50, |&x| x >= target)
+            );
+        }
+    }
 }
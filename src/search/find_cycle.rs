@@ -0,0 +1,121 @@
+use std::hash::Hash;
+
+use crate::prelude::HashMap;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    // On the current DFS path
+    Gray,
+    // Fully explored
+    Black,
+}
+
+/// Cycle detection in a directed graph
+///
+/// Runs a depth-first search from each of the given `nodes` and returns the
+/// first cycle found as the sequence of nodes along it (the first node is not
+/// repeated at the end), or `None` if the graph is acyclic.
+///
+/// Unlike cycle detection in functional graphs, each node may have any number
+/// of successors. A self-loop is returned as a cycle of length one.
+///
+/// # Arguments
+///
+/// * `nodes` - The nodes to start the search from. Nodes that are only reachable via successors are explored as well.
+/// * `successors` - Returns the successors of a node.
+///
+pub fn find_cycle<N, FN, IN>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: FN,
+) -> Option<Vec<N>>
+where
+    N: Hash + Eq + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut color: HashMap<N, Color> = HashMap::default();
+
+    for root in nodes {
+        if color.contains_key(&root) {
+            continue;
+        }
+
+        color.insert(root.clone(), Color::Gray);
+        let mut stack = vec![(root.clone(), successors(&root).into_iter())];
+
+        while let Some((_, iter)) = stack.last_mut() {
+            let Some(next) = iter.next() else {
+                let (node, _) = stack.pop().unwrap();
+                color.insert(node, Color::Black);
+                continue;
+            };
+
+            match color.get(&next) {
+                Some(Color::Black) => {}
+
+                Some(Color::Gray) => {
+                    let start = stack.iter().position(|(node, _)| *node == next).unwrap();
+                    return Some(stack.drain(start..).map(|(node, _)| node).collect());
+                }
+
+                None => {
+                    color.insert(next.clone(), Color::Gray);
+                    let iter = successors(&next).into_iter();
+                    stack.push((next, iter));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(u32, u32)]) -> impl FnMut(&u32) -> Vec<u32> + '_ {
+        move |n| {
+            edges
+                .iter()
+                .filter(|(from, _)| from == n)
+                .map(|(_, to)| *to)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let edges = [(1, 2), (2, 3), (3, 4), (4, 2), (1, 5)];
+        let cycle = find_cycle([1], graph(&edges)).unwrap();
+
+        assert_eq!(cycle, vec![2, 3, 4]);
+
+        for (i, node) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            assert!(edges.contains(&(*node, next)));
+        }
+    }
+
+    #[test]
+    fn test_find_cycle_acyclic() {
+        // A diamond has two paths to the same node, but no cycle
+        let edges = [(1, 2), (1, 3), (2, 4), (3, 4), (4, 5)];
+
+        assert_eq!(find_cycle([1, 2, 3, 4, 5], graph(&edges)), None);
+    }
+
+    #[test]
+    fn test_find_cycle_self_loop() {
+        let edges = [(1, 2), (2, 2)];
+
+        assert_eq!(find_cycle([1], graph(&edges)), Some(vec![2]));
+    }
+
+    #[test]
+    fn test_find_cycle_later_component() {
+        let edges = [(1, 2), (3, 4), (4, 3)];
+
+        assert_eq!(find_cycle([1, 2, 3, 4], graph(&edges)), Some(vec![3, 4]));
+    }
+}
@@ -1,5 +1,13 @@
+mod astar;
 mod beam;
 mod bisect;
+mod find_cycle;
+mod topological_layers;
+mod two_sat;
 
+pub use astar::*;
 pub use beam::*;
 pub use bisect::*;
+pub use find_cycle::*;
+pub use topological_layers::*;
+pub use two_sat::*;
@@ -0,0 +1,146 @@
+use std::hash::Hash;
+
+use crate::prelude::HashMap;
+
+use super::find_cycle;
+
+/// Layered topological sort
+///
+/// Groups the nodes of a directed graph into layers such that every edge goes
+/// from an earlier layer to a later one, i.e. every node only depends on nodes
+/// in earlier layers (Kahn's algorithm). The number of layers is the number of
+/// rounds needed if all nodes of a layer are processed in parallel.
+///
+/// Within a layer, nodes are ordered by when they were first seen.
+///
+/// Returns `Err` with the nodes of a cycle (in order) if the graph is not
+/// acyclic.
+///
+/// # Arguments
+///
+/// * `nodes` - The nodes of the graph. Nodes that are only reachable via successors are included as well.
+/// * `successors` - Returns the successors of a node, i.e. the nodes that depend on it.
+///
+pub fn topological_layers<N, FN, IN>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: FN,
+) -> Result<Vec<Vec<N>>, Vec<N>>
+where
+    N: Hash + Eq + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+{
+    let mut index: HashMap<N, usize> = HashMap::default();
+    let mut all_nodes = Vec::new();
+
+    for node in nodes {
+        if !index.contains_key(&node) {
+            index.insert(node.clone(), all_nodes.len());
+            all_nodes.push(node);
+        }
+    }
+
+    // Collect the edges, discovering nodes that are only reachable via
+    // successors along the way.
+    let mut edges: Vec<Vec<usize>> = Vec::new();
+    let mut i = 0;
+
+    while i < all_nodes.len() {
+        let mut out = Vec::new();
+
+        for succ in successors(&all_nodes[i]) {
+            let j = *index.entry(succ.clone()).or_insert_with(|| {
+                all_nodes.push(succ);
+                all_nodes.len() - 1
+            });
+
+            out.push(j);
+        }
+
+        edges.push(out);
+        i += 1;
+    }
+
+    let mut in_degree = vec![0; all_nodes.len()];
+
+    for &j in edges.iter().flatten() {
+        in_degree[j] += 1;
+    }
+
+    let mut layer: Vec<usize> = (0..all_nodes.len())
+        .filter(|&i| in_degree[i] == 0)
+        .collect();
+
+    let mut layers = Vec::new();
+    let mut placed = 0;
+
+    while !layer.is_empty() {
+        let mut next_layer = Vec::new();
+
+        for &i in layer.iter() {
+            for &j in edges[i].iter() {
+                in_degree[j] -= 1;
+
+                if in_degree[j] == 0 {
+                    next_layer.push(j);
+                }
+            }
+        }
+
+        placed += layer.len();
+        layers.push(layer);
+        layer = next_layer;
+    }
+
+    if placed < all_nodes.len() {
+        let remaining = (0..all_nodes.len()).filter(|&i| in_degree[i] > 0);
+        let cycle = find_cycle(remaining, |&i| edges[i].clone())
+            .expect("Nodes that could not be placed must be part of a cycle");
+
+        return Err(cycle.into_iter().map(|i| all_nodes[i].clone()).collect());
+    }
+
+    Ok(layers
+        .into_iter()
+        .map(|layer| layer.into_iter().map(|i| all_nodes[i].clone()).collect())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(char, char)]) -> impl FnMut(&char) -> Vec<char> + '_ {
+        move |n| {
+            edges
+                .iter()
+                .filter(|(from, _)| from == n)
+                .map(|(_, to)| *to)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_topological_layers() {
+        let edges = [('a', 'c'), ('b', 'c'), ('c', 'd'), ('a', 'd'), ('e', 'f')];
+        let layers = topological_layers("abcdef".chars(), graph(&edges)).unwrap();
+
+        assert_eq!(layers, vec![vec!['a', 'b', 'e'], vec!['c', 'f'], vec!['d']]);
+    }
+
+    #[test]
+    fn test_topological_layers_discovers_nodes() {
+        let edges = [('a', 'b'), ('b', 'c')];
+        let layers = topological_layers(['a'], graph(&edges)).unwrap();
+
+        assert_eq!(layers, vec![vec!['a'], vec!['b'], vec!['c']]);
+    }
+
+    #[test]
+    fn test_topological_layers_cycle() {
+        let edges = [('a', 'b'), ('b', 'c'), ('c', 'd'), ('d', 'b'), ('d', 'e')];
+        let cycle = topological_layers("abcde".chars(), graph(&edges)).unwrap_err();
+
+        assert_eq!(cycle, vec!['b', 'c', 'd']);
+    }
+}
@@ -0,0 +1,228 @@
+/// 2-SAT solver
+///
+/// Solves boolean formulas in conjunctive normal form where every clause has
+/// (at most) two literals, by building the implication graph and finding its
+/// strongly connected components.
+///
+/// Variables are numbered from 1, and literals are given as signed integers:
+/// `3` means "variable 3 is true" and `-3` means "variable 3 is false".
+///
+/// # Example
+///
+/// ```
+/// # use utility_belt::prelude::*;
+/// let mut sat = TwoSat::new(2);
+/// sat.add_clause(1, 2);   // x1 or x2
+/// sat.add_clause(-1, -1); // not x1
+///
+/// assert_eq!(sat.solve(), Some(vec![false, true]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TwoSat {
+    num_vars: usize,
+    implications: Vec<Vec<usize>>,
+}
+
+impl TwoSat {
+    pub fn new(num_vars: usize) -> Self {
+        Self {
+            num_vars,
+            implications: vec![Vec::new(); 2 * num_vars],
+        }
+    }
+
+    /// Adds the clause `a or b`. Use `add_clause(a, a)` to force `a` to hold.
+    ///
+    /// Panics if a literal is 0 or refers to a variable that does not exist.
+    pub fn add_clause(&mut self, a: i32, b: i32) {
+        let a = self.node(a);
+        let b = self.node(b);
+
+        // (a or b) is equivalent to (not a => b) and (not b => a)
+        self.implications[a ^ 1].push(b);
+        self.implications[b ^ 1].push(a);
+    }
+
+    /// Returns an assignment that satisfies all clauses (indexed from 0, i.e.
+    /// `result[0]` is the value of variable 1), or `None` if there is none.
+    pub fn solve(&self) -> Option<Vec<bool>> {
+        let component = strongly_connected_components(&self.implications);
+
+        (0..self.num_vars)
+            .map(|v| {
+                let (pos, neg) = (component[2 * v], component[2 * v + 1]);
+
+                if pos == neg {
+                    return None;
+                }
+
+                // Components are numbered in reverse topological order, so the
+                // literal that comes later in the implication order is set.
+                Some(pos < neg)
+            })
+            .collect()
+    }
+
+    // Node 2(v-1) stands for variable v, node 2(v-1)+1 for its negation.
+    fn node(&self, literal: i32) -> usize {
+        assert!(literal != 0, "Literals are 1-based");
+
+        let var = literal.unsigned_abs() as usize - 1;
+        assert!(var < self.num_vars, "Variable {} does not exist", var + 1);
+
+        2 * var + (literal < 0) as usize
+    }
+}
+
+// Tarjan's algorithm (iterative). Returns the component index of every node;
+// components are numbered in reverse topological order.
+fn strongly_connected_components(graph: &[Vec<usize>]) -> Vec<usize> {
+    const UNVISITED: usize = usize::MAX;
+
+    let n = graph.len();
+    let mut index = vec![UNVISITED; n];
+    let mut lowlink = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut component = vec![UNVISITED; n];
+
+    let mut stack = Vec::new();
+    let mut next_index = 0;
+    let mut next_component = 0;
+
+    for root in 0..n {
+        if index[root] != UNVISITED {
+            continue;
+        }
+
+        // (node, position in its successor list)
+        let mut call_stack = vec![(root, 0)];
+
+        index[root] = next_index;
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&mut (node, ref mut pos)) = call_stack.last_mut() {
+            if let Some(&succ) = graph[node].get(*pos) {
+                *pos += 1;
+
+                if index[succ] == UNVISITED {
+                    index[succ] = next_index;
+                    lowlink[succ] = next_index;
+                    next_index += 1;
+                    stack.push(succ);
+                    on_stack[succ] = true;
+                    call_stack.push((succ, 0));
+                } else if on_stack[succ] {
+                    lowlink[node] = lowlink[node].min(index[succ]);
+                }
+
+                continue;
+            }
+
+            call_stack.pop();
+
+            if let Some(&(parent, _)) = call_stack.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[node]);
+            }
+
+            if lowlink[node] == index[node] {
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack[member] = false;
+                    component[member] = next_component;
+
+                    if member == node {
+                        break;
+                    }
+                }
+
+                next_component += 1;
+            }
+        }
+    }
+
+    component
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn satisfies(clauses: &[(i32, i32)], assignment: &[bool]) -> bool {
+        let value = |lit: i32| assignment[lit.unsigned_abs() as usize - 1] == (lit > 0);
+
+        clauses.iter().all(|&(a, b)| value(a) || value(b))
+    }
+
+    #[test]
+    fn test_satisfiable() {
+        let clauses = [(1, 2), (-1, 3), (-2, -3), (2, 4), (-4, -1)];
+        let mut sat = TwoSat::new(4);
+
+        for &(a, b) in clauses.iter() {
+            sat.add_clause(a, b);
+        }
+
+        let assignment = sat.solve().unwrap();
+        assert!(satisfies(&clauses, &assignment));
+    }
+
+    #[test]
+    fn test_unsatisfiable() {
+        let mut sat = TwoSat::new(2);
+
+        sat.add_clause(1, 2);
+        sat.add_clause(1, -2);
+        sat.add_clause(-1, 2);
+        sat.add_clause(-1, -2);
+
+        assert_eq!(sat.solve(), None);
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        // Small deterministic pseudo-random instances
+        let mut seed = 12345u32;
+        let mut next = |m: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) % m
+        };
+
+        for _ in 0..200 {
+            let num_vars = 1 + next(5) as usize;
+            let num_clauses = next(8);
+            let mut literal = || {
+                let v = 1 + next(num_vars as u32) as i32;
+                if next(2) == 0 {
+                    v
+                } else {
+                    -v
+                }
+            };
+
+            let clauses = (0..num_clauses)
+                .map(|_| (literal(), literal()))
+                .collect::<Vec<_>>();
+
+            let mut sat = TwoSat::new(num_vars);
+
+            for &(a, b) in clauses.iter() {
+                sat.add_clause(a, b);
+            }
+
+            let brute_force = (0..1u32 << num_vars).any(|mask| {
+                let assignment = (0..num_vars)
+                    .map(|i| mask & (1 << i) != 0)
+                    .collect::<Vec<_>>();
+                satisfies(&clauses, &assignment)
+            });
+
+            match sat.solve() {
+                Some(assignment) => assert!(satisfies(&clauses, &assignment)),
+                None => assert!(!brute_force),
+            }
+        }
+    }
+}
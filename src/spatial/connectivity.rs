@@ -0,0 +1,41 @@
+use super::Direction;
+
+/// Determines which cells count as neighbors of a given cell
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// The four cardinal neighbors (von Neumann neighborhood)
+    #[default]
+    VonNeumann,
+
+    /// All eight surrounding cells (Moore neighborhood)
+    Moore,
+}
+
+impl Connectivity {
+    /// Returns an iterator over the directions leading to a neighbor.
+    ///
+    /// The cardinal directions come first, in the same order as
+    /// `Direction::cardinal()`.
+    pub fn directions(self) -> impl Iterator<Item = Direction> {
+        let count = match self {
+            Self::VonNeumann => 4,
+            Self::Moore => 8,
+        };
+
+        Direction::all().take(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directions() {
+        assert!(Connectivity::VonNeumann
+            .directions()
+            .eq(Direction::cardinal()));
+
+        assert!(Connectivity::Moore.directions().eq(Direction::all()));
+    }
+}
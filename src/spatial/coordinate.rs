@@ -3,9 +3,9 @@ use std::{
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 };
 
-use num::{rational::Ratio, FromPrimitive, Num, Rational64, Signed};
+use num::{rational::Ratio, traits::Euclid, FromPrimitive, Num, Rational64, Signed};
 
-use super::Direction;
+use super::{Connectivity, Direction};
 
 pub trait CoordinateNum: Num + Copy + PartialOrd + PartialEq + Neg<Output = Self> + Signed {}
 
@@ -22,6 +22,7 @@ impl CoordinateNum for Ratio<i128> {}
 
 /// A coordinate in a 2D grid.
 #[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coordinate<T = i32>
 where
     T: CoordinateNum,
@@ -119,6 +120,44 @@ where
         Direction::all().map(move |dir| self + dir)
     }
 
+    /// Return all coordinates within the given radius of self, excluding self.
+    ///
+    /// The radius is measured as Chebyshev distance for `Connectivity::Moore`
+    /// and as Manhattan distance for `Connectivity::VonNeumann`. The
+    /// coordinates are returned in row-major order.
+    pub fn neighbors_in_radius(
+        self,
+        radius: T,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = Self> {
+        let offsets = move || {
+            let first = if -radius <= radius {
+                Some(-radius)
+            } else {
+                None
+            };
+
+            std::iter::successors(first, move |&v| {
+                let next = v + T::one();
+
+                if next <= radius {
+                    Some(next)
+                } else {
+                    None
+                }
+            })
+        };
+
+        offsets()
+            .flat_map(move |dy| offsets().map(move |dx| Self::new(dx, dy)))
+            .filter(move |d| !(d.x.is_zero() && d.y.is_zero()))
+            .filter(move |d| match connectivity {
+                Connectivity::Moore => true,
+                Connectivity::VonNeumann => d.x.abs() + d.y.abs() <= radius,
+            })
+            .map(move |d| self + d)
+    }
+
     /// Return a list of all coordinates reachable from self by a knight's move
     pub fn knight_move_neighbors(self) -> impl Iterator<Item = Self> {
         use Direction::*;
@@ -151,6 +190,38 @@ where
         x_max - x_min + y_max - y_min
     }
 
+    /// Returns the number of lattice points within Manhattan distance `radius`
+    /// of a point, i.e. `2r(r + 1) + 1`.
+    ///
+    /// Returns 0 for a negative radius.
+    pub fn manhattan_disk_area(radius: T) -> T {
+        if radius < T::zero() {
+            return T::zero();
+        }
+
+        let two = T::one() + T::one();
+
+        two * radius * (radius + T::one()) + T::one()
+    }
+
+    /// Clamps each axis of the coordinate independently into `[min, max]`
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        let clamp_axis = |v: T, lo: T, hi: T| {
+            if v < lo {
+                lo
+            } else if v > hi {
+                hi
+            } else {
+                v
+            }
+        };
+
+        Self::new(
+            clamp_axis(self.x, min.x, max.x),
+            clamp_axis(self.y, min.y, max.y),
+        )
+    }
+
     /// Returns the direction from self towards other
     pub fn towards(self, other: Self) -> Direction {
         if other.x < self.x {
@@ -179,6 +250,58 @@ where
     }
 }
 
+impl<T> Coordinate<T>
+where
+    T: CoordinateNum + Euclid,
+{
+    /// Adds `delta` and wraps the result into `[0, bounds)` on each axis,
+    /// i.e. moves across a torus of the given size.
+    pub fn add_wrapping(self, delta: Self, bounds: Self) -> Self {
+        let sum = self + delta;
+
+        Self::new(sum.x.rem_euclid(&bounds.x), sum.y.rem_euclid(&bounds.y))
+    }
+}
+
+impl Coordinate {
+    /// Returns the cells on the line from `self` to `other` (both inclusive),
+    /// as computed by Bresenham's line algorithm.
+    pub fn line_to(self, other: Self) -> impl Iterator<Item = Self> {
+        let dx = (other.x - self.x).abs();
+        let dy = -(other.y - self.y).abs();
+        let step = Self::new((other.x - self.x).signum(), (other.y - self.y).signum());
+
+        let mut cur = Some(self);
+        let mut err = dx + dy;
+
+        std::iter::from_fn(move || {
+            let result = cur?;
+
+            if result == other {
+                cur = None;
+                return Some(result);
+            }
+
+            let mut next = result;
+            let e2 = 2 * err;
+
+            if e2 >= dy {
+                err += dy;
+                next.x += step.x;
+            }
+
+            if e2 <= dx {
+                err += dx;
+                next.y += step.y;
+            }
+
+            cur = Some(next);
+
+            Some(result)
+        })
+    }
+}
+
 impl<T> From<Direction> for Coordinate<T>
 where
     T: CoordinateNum,
@@ -224,13 +347,19 @@ where
     }
 }
 
-impl Ord for Coordinate {
+impl<T> Ord for Coordinate<T>
+where
+    T: CoordinateNum + Ord,
+{
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.y.cmp(&other.y).then(self.x.cmp(&other.x))
     }
 }
 
-impl PartialOrd for Coordinate {
+impl<T> PartialOrd for Coordinate<T>
+where
+    T: CoordinateNum + Ord,
+{
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
@@ -338,6 +467,7 @@ impl RemAssign<Coordinate> for Coordinate {
 
 #[cfg(test)]
 mod tests {
+    use itertools::Itertools;
     use num::Rational64;
     use rstest::rstest;
 
@@ -512,6 +642,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_neighbors_in_radius() {
+        let center = Coordinate::new(5, 5);
+
+        let moore = center
+            .neighbors_in_radius(1, Connectivity::Moore)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            moore,
+            vec![
+                Coordinate::new(4, 4),
+                Coordinate::new(5, 4),
+                Coordinate::new(6, 4),
+                Coordinate::new(4, 5),
+                Coordinate::new(6, 5),
+                Coordinate::new(4, 6),
+                Coordinate::new(5, 6),
+                Coordinate::new(6, 6),
+            ]
+        );
+
+        let von_neumann = center
+            .neighbors_in_radius(1, Connectivity::VonNeumann)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            von_neumann,
+            vec![
+                Coordinate::new(5, 4),
+                Coordinate::new(4, 5),
+                Coordinate::new(6, 5),
+                Coordinate::new(5, 6),
+            ]
+        );
+
+        assert_eq!(
+            center.neighbors_in_radius(2, Connectivity::Moore).count(),
+            24
+        );
+
+        assert_eq!(
+            center
+                .neighbors_in_radius(2, Connectivity::VonNeumann)
+                .count(),
+            12
+        );
+
+        assert_eq!(
+            center.neighbors_in_radius(0, Connectivity::Moore).count(),
+            0
+        );
+    }
+
     #[test]
     fn test_knight_move_neighbors() {
         assert_eq!(
@@ -531,6 +715,118 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case((1, 2), (1, 2))]
+    #[case((-5, 2), (0, 2))]
+    #[case((1, 9), (1, 3))]
+    #[case((-1, 7), (0, 3))]
+    fn test_clamp(#[case] input: (i32, i32), #[case] expected: (i32, i32)) {
+        assert_eq!(
+            Coordinate::from(input).clamp(Coordinate::new(0, 0), Coordinate::new(4, 3)),
+            Coordinate::from(expected)
+        );
+    }
+
+    #[test]
+    fn test_clamp_float() {
+        assert_eq!(
+            Coordinate::new(-0.5, 2.5).clamp(Coordinate::new(0.0, 0.0), Coordinate::new(1.0, 1.0)),
+            Coordinate::new(0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_line_to() {
+        let line = |a: (i32, i32), b: (i32, i32)| {
+            Coordinate::from(a)
+                .line_to(b.into())
+                .map(|c| (c.x, c.y))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(line((0, 0), (0, 0)), vec![(0, 0)]);
+        assert_eq!(line((0, 0), (3, 0)), vec![(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(line((2, 2), (0, 0)), vec![(2, 2), (1, 1), (0, 0)]);
+        assert_eq!(
+            line((0, 0), (4, 2)),
+            vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]
+        );
+        assert_eq!(
+            line((0, 0), (-1, -3)),
+            vec![(0, 0), (0, -1), (-1, -2), (-1, -3)]
+        );
+    }
+
+    #[test]
+    fn test_ord_i64() {
+        let mut coords: Vec<Coordinate<i64>> = vec![
+            Coordinate::new(5_000_000_000, 1),
+            Coordinate::new(-3, 1),
+            Coordinate::new(7, -2),
+        ];
+
+        coords.sort();
+
+        assert_eq!(
+            coords,
+            vec![
+                Coordinate::new(7, -2),
+                Coordinate::new(-3, 1),
+                Coordinate::new(5_000_000_000, 1),
+            ]
+        );
+
+        let map: std::collections::BTreeMap<Coordinate<i64>, char> =
+            [(Coordinate::new(1, 1), 'b'), (Coordinate::new(9, 0), 'a')].into();
+
+        assert_eq!(map.values().collect::<String>(), "ab");
+    }
+
+    #[test]
+    fn test_manhattan_disk_area() {
+        for radius in 0..6 {
+            let center = Coordinate::new(0, 0);
+            let count = (-radius..=radius)
+                .cartesian_product(-radius..=radius)
+                .filter(|&(x, y)| center.manhattan_distance(Coordinate::new(x, y)) <= radius)
+                .count();
+
+            assert_eq!(Coordinate::manhattan_disk_area(radius), count as i32);
+        }
+
+        assert_eq!(Coordinate::manhattan_disk_area(-1), 0);
+        assert_eq!(
+            Coordinate::<i64>::manhattan_disk_area(1_000_000),
+            2_000_002_000_001
+        );
+    }
+
+    #[rstest]
+    #[case((1, 1), (1, 1), (2, 2))]
+    #[case((4, 2), (1, 0), (0, 2))]
+    #[case((0, 0), (-1, -1), (4, 2))]
+    #[case((2, 1), (-12, 7), (0, 2))]
+    fn test_add_wrapping(
+        #[case] start: (i32, i32),
+        #[case] delta: (i32, i32),
+        #[case] expected: (i32, i32),
+    ) {
+        assert_eq!(
+            Coordinate::from(start).add_wrapping(delta.into(), Coordinate::new(5, 3)),
+            Coordinate::from(expected)
+        );
+    }
+
+    #[test]
+    fn test_add_wrapping_i64() {
+        let c: Coordinate<i64> = Coordinate::new(0, 0);
+
+        assert_eq!(
+            c.add_wrapping(Coordinate::new(-1, 10), Coordinate::new(100, 10)),
+            Coordinate::new(99, 0)
+        );
+    }
+
     #[test]
     fn test_display() {
         assert_eq!(format!("{}", Coordinate::new(1, 2)), "(1, 2)");
@@ -0,0 +1,227 @@
+use std::{
+    fmt::{Debug, Display, Formatter},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use super::CoordinateNum;
+
+/// A coordinate in 3D space.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coordinate3D<T = i32>
+where
+    T: CoordinateNum,
+{
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the six face-adjacent neighbors (6-connectivity), in the order
+    /// -x, +x, -y, +y, -z, +z.
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        let (zero, one) = (T::zero(), T::one());
+
+        [
+            Self::new(-one, zero, zero),
+            Self::new(one, zero, zero),
+            Self::new(zero, -one, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, zero, -one),
+            Self::new(zero, zero, one),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+
+    /// Returns whether the two coordinates share a face
+    pub fn adjacent(self, other: Self) -> bool {
+        self.manhattan_distance(other) == T::one()
+    }
+
+    /// Returns the Manhattan distance between the two coordinates
+    pub fn manhattan_distance(self, other: Self) -> T {
+        let d = self - other;
+
+        d.x.abs() + d.y.abs() + d.z.abs()
+    }
+}
+
+impl<T> From<(T, T, T)> for Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl<T> Display for Coordinate3D<T>
+where
+    T: CoordinateNum + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> Debug for Coordinate3D<T>
+where
+    T: CoordinateNum + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?}, {:?})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> Add for Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T> AddAssign for Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T> Sub for Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T> SubAssign for Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> Neg for Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T> Mul<T> for Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self {
+        Self::new(self.x * other, self.y * other, self.z * other)
+    }
+}
+
+impl<T> MulAssign<T> for Coordinate3D<T>
+where
+    T: CoordinateNum,
+{
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Coordinate3D::new(1, 2, 3);
+        let b = Coordinate3D::from((-1, 0, 5));
+
+        assert_eq!(a + b, Coordinate3D::new(0, 2, 8));
+        assert_eq!(a - b, Coordinate3D::new(2, 2, -2));
+        assert_eq!(-a, Coordinate3D::new(-1, -2, -3));
+        assert_eq!(a * 2, Coordinate3D::new(2, 4, 6));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 3;
+        assert_eq!(c, Coordinate3D::new(-3, 0, 15));
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Coordinate3D::new(1, 2, 3);
+
+        assert_eq!(a.manhattan_distance(Coordinate3D::new(-1, 4, 0)), 7);
+        assert_eq!(a.manhattan_distance(a), 0);
+        assert!(a.adjacent(Coordinate3D::new(1, 2, 4)));
+        assert!(!a.adjacent(Coordinate3D::new(1, 3, 4)));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let origin = Coordinate3D::<i64>::default();
+        let neighbors = origin.neighbors().collect::<Vec<_>>();
+
+        assert_eq!(neighbors.len(), 6);
+        assert!(neighbors.iter().all(|n| n.adjacent(origin)));
+    }
+
+    #[test]
+    fn test_surface_area() {
+        // AoC 2022, day 18 example
+        let cubes: HashSet<Coordinate3D> = [
+            (2, 2, 2),
+            (1, 2, 2),
+            (3, 2, 2),
+            (2, 1, 2),
+            (2, 3, 2),
+            (2, 2, 1),
+            (2, 2, 3),
+            (2, 2, 4),
+            (2, 2, 6),
+            (1, 2, 5),
+            (3, 2, 5),
+            (2, 1, 5),
+            (2, 3, 5),
+        ]
+        .into_iter()
+        .map(Coordinate3D::from)
+        .collect();
+
+        let exposed = cubes
+            .iter()
+            .flat_map(|c| c.neighbors())
+            .filter(|n| !cubes.contains(n))
+            .count();
+
+        assert_eq!(exposed, 64);
+    }
+}
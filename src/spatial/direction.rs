@@ -1,9 +1,10 @@
 use std::ops::{Add, Mul};
 
-use super::Coordinate;
+use super::{Coordinate, CoordinateNum};
 
 /// An enum representing the eight directions in a grid
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Direction {
     #[default]
@@ -117,6 +118,36 @@ impl Direction {
             Self::UpLeft => Self::DownRight,
         }
     }
+
+    /// Returns the offset of a single step in this direction
+    pub fn offset<T: CoordinateNum>(self) -> Coordinate<T> {
+        self.into()
+    }
+
+    /// Returns the offset of `n` steps in this direction
+    pub fn scaled<T: CoordinateNum>(self, n: T) -> Coordinate<T> {
+        self.offset() * n
+    }
+
+    /// Returns the signed number of 45 degree steps needed to turn from `self`
+    /// to `other`, taking the shorter way around.
+    ///
+    /// Negative values are turns to the left, positive values turns to the
+    /// right. Turning around (180 degrees) is reported as `4`.
+    pub fn turns_to(self, other: Direction) -> i8 {
+        // Clockwise position of each direction, indexed by its u8 value
+        const OCTANT: [i8; 8] = [0, 2, 4, 6, 7, 1, 5, 3];
+
+        let from = OCTANT[u8::from(self) as usize];
+        let to = OCTANT[u8::from(other) as usize];
+        let diff = (to - from).rem_euclid(8);
+
+        if diff > 4 {
+            diff - 8
+        } else {
+            diff
+        }
+    }
 }
 
 impl TryFrom<char> for Direction {
@@ -222,9 +253,7 @@ impl Mul<i32> for Direction {
     type Output = Coordinate;
 
     fn mul(self, steps: i32) -> Self::Output {
-        let coord: Coordinate = self.into();
-
-        coord * steps
+        self.scaled(steps)
     }
 }
 
@@ -238,6 +267,8 @@ impl Mul<Direction> for i32 {
 
 #[cfg(test)]
 mod tests {
+    use num::Rational64;
+
     use super::*;
 
     #[test]
@@ -245,6 +276,46 @@ mod tests {
         assert_eq!(Direction::Up + Direction::Up, Coordinate::new(0, -2));
     }
 
+    #[test]
+    fn test_offset() {
+        assert_eq!(
+            Direction::UpLeft.offset::<i64>(),
+            Coordinate::new(-1i64, -1)
+        );
+        assert_eq!(Direction::Right.offset::<f64>(), Coordinate::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_scaled() {
+        assert_eq!(Direction::DownLeft.scaled(3i64), Coordinate::new(-3i64, 3));
+
+        assert_eq!(
+            Direction::Up.scaled(Rational64::new(1, 2)),
+            Coordinate::new(Rational64::from(0), Rational64::new(-1, 2))
+        );
+    }
+
+    #[test]
+    fn test_turns_to() {
+        use Direction::*;
+
+        assert_eq!(Up.turns_to(Up), 0);
+        assert_eq!(Up.turns_to(UpRight), 1);
+        assert_eq!(Up.turns_to(Right), 2);
+        assert_eq!(Up.turns_to(Left), -2);
+        assert_eq!(Up.turns_to(UpLeft), -1);
+        assert_eq!(Up.turns_to(Down), 4);
+        assert_eq!(Left.turns_to(Up), 2);
+        assert_eq!(Left.turns_to(DownRight), -3);
+        assert_eq!(UpLeft.turns_to(Right), 3);
+
+        for a in Direction::all() {
+            assert_eq!(a.turns_to(a.turn_right_45()), 1);
+            assert_eq!(a.turns_to(a.turn_left_90()), -2);
+            assert_eq!(a.turns_to(a.opposite()), 4);
+        }
+    }
+
     #[test]
     fn test_mul() {
         assert_eq!(Direction::Up * 2, Coordinate::new(0, -2));
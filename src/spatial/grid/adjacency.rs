@@ -0,0 +1,117 @@
+use std::fmt::Write;
+
+use crate::prelude::{Connectivity, Coordinate, Grid2D};
+
+impl<T: Clone> Grid2D<T> {
+    /// Converts the grid into a plain node and edge list.
+    ///
+    /// Returns the coordinates of the passable cells in row-major order, and
+    /// the edges between adjacent passable cells as pairs of indices into that
+    /// list. Edges are undirected and listed once, with the smaller index
+    /// first.
+    pub fn to_adjacency(
+        &self,
+        passable: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> (Vec<Coordinate>, Vec<(usize, usize)>) {
+        let mut nodes = Vec::new();
+
+        let mut indices = self.map(|_| None::<usize>);
+
+        for (coord, value) in self.iter() {
+            if passable(value) {
+                indices[coord] = Some(nodes.len());
+                nodes.push(coord);
+            }
+        }
+
+        let mut edges = Vec::new();
+
+        for (i, &coord) in nodes.iter().enumerate() {
+            for (neighbor, _) in self.neighbors_of(coord, connectivity) {
+                if let Some(j) = indices[neighbor] {
+                    if i < j {
+                        edges.push((i, j));
+                    }
+                }
+            }
+        }
+
+        (nodes, edges)
+    }
+
+    /// Renders the graph from [`Grid2D::to_adjacency`] in the Graphviz DOT
+    /// format for debugging. Nodes are labeled with their coordinates.
+    pub fn to_dot(&self, passable: impl Fn(&T) -> bool, connectivity: Connectivity) -> String {
+        let (nodes, edges) = self.to_adjacency(passable, connectivity);
+
+        let mut dot = String::from("graph {\n");
+
+        for (i, coord) in nodes.iter().enumerate() {
+            writeln!(dot, "  n{i} [label=\"{coord}\"];").unwrap();
+        }
+
+        for (i, j) in edges {
+            writeln!(dot, "  n{i} -- n{j};").unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_to_adjacency() {
+        let grid: Grid2D<char> = indoc! {"
+            ..#
+            .#.
+        "}
+        .into();
+
+        let passable = |c: &char| *c == '.';
+
+        let (nodes, edges) = grid.to_adjacency(passable, Connectivity::VonNeumann);
+
+        assert_eq!(
+            nodes,
+            vec![
+                Coordinate::new(0, 0),
+                Coordinate::new(1, 0),
+                Coordinate::new(0, 1),
+                Coordinate::new(2, 1),
+            ]
+        );
+        assert_eq!(edges, vec![(0, 1), (0, 2)]);
+
+        let (_, edges) = grid.to_adjacency(passable, Connectivity::Moore);
+        assert_eq!(edges, vec![(0, 1), (0, 2), (1, 2), (1, 3)]);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let grid: Grid2D<char> = indoc! {"
+            .#
+            .#
+        "}
+        .into();
+
+        let dot = grid.to_dot(|c| *c == '.', Connectivity::VonNeumann);
+
+        assert_eq!(
+            dot,
+            indoc! {r#"
+                graph {
+                  n0 [label="(0, 0)"];
+                  n1 [label="(0, 1)"];
+                  n0 -- n1;
+                }
+            "#}
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use crate::prelude::{Coordinate, Grid2D};
+
+/// The distance metric used by [`Grid2D::distance_transform`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// The Manhattan (taxicab) distance, |dx| + |dy|
+    Manhattan,
+
+    /// The exact squared Euclidean distance, dx² + dy²
+    EuclideanSquared,
+}
+
+impl<T: Clone> Grid2D<T> {
+    /// Computes the distance from every cell to the nearest feature cell (a
+    /// cell for which `is_feature` returns true). Feature cells have a
+    /// distance of 0.
+    ///
+    /// Unlike a breadth-first search, this ignores obstacles: the distance is
+    /// measured in a straight line through the plane. If there are no feature
+    /// cells at all, every cell is `u32::MAX`.
+    ///
+    /// Manhattan distances are computed with a two-pass chamfer sweep and
+    /// squared Euclidean distances with the algorithm of Felzenszwalb and
+    /// Huttenlocher; both run in linear time.
+    #[must_use]
+    pub fn distance_transform(
+        &self,
+        is_feature: impl Fn(&T) -> bool,
+        metric: Metric,
+    ) -> Grid2D<u32> {
+        let features = self.map(is_feature);
+
+        match metric {
+            Metric::Manhattan => manhattan_transform(&features),
+            Metric::EuclideanSquared => euclidean_squared_transform(&features),
+        }
+    }
+}
+
+fn manhattan_transform(features: &Grid2D<bool>) -> Grid2D<u32> {
+    let mut dist = features.map(|&f| if f { 0 } else { u32::MAX });
+    let (w, h) = (features.width, features.height);
+
+    let relax = |dist: &mut Grid2D<u32>, cur: Coordinate, prev: Coordinate| {
+        let candidate = dist[prev].saturating_add(1);
+
+        if candidate < dist[cur] {
+            dist[cur] = candidate;
+        }
+    };
+
+    // Forward pass: top-left to bottom-right
+    for y in 0..h {
+        for x in 0..w {
+            let cur = Coordinate::new(x, y);
+
+            if x > 0 {
+                relax(&mut dist, cur, Coordinate::new(x - 1, y));
+            }
+
+            if y > 0 {
+                relax(&mut dist, cur, Coordinate::new(x, y - 1));
+            }
+        }
+    }
+
+    // Backward pass: bottom-right to top-left
+    for y in (0..h).rev() {
+        for x in (0..w).rev() {
+            let cur = Coordinate::new(x, y);
+
+            if x < w - 1 {
+                relax(&mut dist, cur, Coordinate::new(x + 1, y));
+            }
+
+            if y < h - 1 {
+                relax(&mut dist, cur, Coordinate::new(x, y + 1));
+            }
+        }
+    }
+
+    dist
+}
+
+// Larger than any squared distance that can occur in a grid, but small enough
+// that adding squared coordinates to it can't overflow.
+const INF: i64 = 1 << 62;
+
+fn euclidean_squared_transform(features: &Grid2D<bool>) -> Grid2D<u32> {
+    let (w, h) = (features.width(), features.height());
+
+    let mut dist = features.map(|&f| if f { 0 } else { INF });
+    let mut buffer = Vec::with_capacity(w.max(h));
+
+    // Columns first, then rows
+    for x in 0..w as i32 {
+        buffer.clear();
+        buffer.extend((0..h as i32).map(|y| dist[Coordinate::new(x, y)]));
+
+        for (y, d) in transform_1d(&buffer).into_iter().enumerate() {
+            dist[Coordinate::new(x, y as i32)] = d;
+        }
+    }
+
+    for y in 0..h as i32 {
+        buffer.clear();
+        buffer.extend((0..w as i32).map(|x| dist[Coordinate::new(x, y)]));
+
+        for (x, d) in transform_1d(&buffer).into_iter().enumerate() {
+            dist[Coordinate::new(x as i32, y)] = d;
+        }
+    }
+
+    dist.map(|&d| if d >= INF { u32::MAX } else { d as u32 })
+}
+
+// One-dimensional squared distance transform of a sampled function: computes
+// `min_q (q - p)² + f(q)` for every `p` using the lower envelope of the
+// parabolas rooted at each `q`.
+fn transform_1d(f: &[i64]) -> Vec<i64> {
+    let n = f.len();
+
+    // Only finite samples contribute parabolas.
+    let roots = (0..n).filter(|&q| f[q] < INF).collect::<Vec<_>>();
+
+    if roots.is_empty() {
+        return vec![INF; n];
+    }
+
+    // Horizontal position where the parabolas rooted at `r` and `q` intersect
+    let intersection = |r: usize, q: usize| {
+        let (fr, fq) = (f[r], f[q]);
+        let (r, q) = (r as i64, q as i64);
+
+        ((fq + q * q) - (fr + r * r)) as f64 / (2 * (q - r)) as f64
+    };
+
+    // The envelope: parabola roots and the x-coordinates where they start
+    let mut envelope: Vec<usize> = vec![roots[0]];
+    let mut starts: Vec<f64> = vec![f64::NEG_INFINITY];
+
+    for &q in &roots[1..] {
+        let mut s = intersection(*envelope.last().unwrap(), q);
+
+        while s <= *starts.last().unwrap() {
+            envelope.pop();
+            starts.pop();
+            s = intersection(*envelope.last().unwrap(), q);
+        }
+
+        envelope.push(q);
+        starts.push(s);
+    }
+
+    let mut k = 0;
+
+    (0..n)
+        .map(|p| {
+            while k + 1 < envelope.len() && starts[k + 1] < p as f64 {
+                k += 1;
+            }
+
+            let d = p as i64 - envelope[k] as i64;
+            d * d + f[envelope[k]]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn naive(grid: &Grid2D<char>, metric: Metric) -> Grid2D<u32> {
+        let features = grid
+            .iter()
+            .filter(|(_, &c)| c == '#')
+            .map(|(coord, _)| coord)
+            .collect::<Vec<_>>();
+
+        let data = grid
+            .iter()
+            .map(|(coord, _)| {
+                features
+                    .iter()
+                    .map(|&f| {
+                        let (dx, dy) = (coord.x - f.x, coord.y - f.y);
+
+                        match metric {
+                            Metric::Manhattan => (dx.abs() + dy.abs()) as u32,
+                            Metric::EuclideanSquared => (dx * dx + dy * dy) as u32,
+                        }
+                    })
+                    .min()
+                    .unwrap_or(u32::MAX)
+            })
+            .collect();
+
+        Grid2D::from_shape_vec(grid.width(), grid.height(), data)
+    }
+
+    #[test]
+    fn test_distance_transform_manhattan() {
+        let grid: Grid2D<char> = indoc! {"
+            #....
+            .....
+            ....#
+        "}
+        .into();
+
+        let dist = grid.distance_transform(|c| *c == '#', Metric::Manhattan);
+
+        assert_eq!(
+            dist,
+            Grid2D::from_shape_vec(5, 3, vec![0, 1, 2, 3, 2, 1, 2, 3, 2, 1, 2, 3, 2, 1, 0])
+        );
+    }
+
+    #[test]
+    fn test_distance_transform_matches_naive() {
+        let grid: Grid2D<char> = indoc! {"
+            .........#..
+            ............
+            ..#.........
+            ............
+            ............
+            .......#....
+            #...........
+            ............
+        "}
+        .into();
+
+        for metric in [Metric::Manhattan, Metric::EuclideanSquared] {
+            assert_eq!(
+                grid.distance_transform(|c| *c == '#', metric),
+                naive(&grid, metric)
+            );
+        }
+    }
+
+    #[test]
+    fn test_distance_transform_without_features() {
+        let grid = Grid2D::new(3, 2, '.');
+
+        for metric in [Metric::Manhattan, Metric::EuclideanSquared] {
+            let dist = grid.distance_transform(|c| *c == '#', metric);
+            assert!(dist.iter().all(|(_, &d)| d == u32::MAX));
+        }
+    }
+}
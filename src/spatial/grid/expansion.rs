@@ -0,0 +1,167 @@
+use crate::prelude::{Coordinate, Grid2D};
+
+/// Maps coordinates of a grid into a space in which every empty row and
+/// column has been expanded by some factor, without materializing the
+/// expanded grid. See [`Grid2D::expand_empty`].
+#[derive(Clone, Debug)]
+pub struct ExpansionMap {
+    factor: usize,
+    // Number of empty rows/columns before each index (with one extra entry for
+    // the total)
+    empty_rows_before: Vec<usize>,
+    empty_cols_before: Vec<usize>,
+}
+
+impl ExpansionMap {
+    /// Translates a coordinate of the original grid into the expanded space.
+    ///
+    /// Coordinates outside of the grid are shifted by all empty rows/columns
+    /// that lie before them.
+    ///
+    /// The expanded coordinates use `i64`, because large factors (e.g.
+    /// 1,000,000) quickly exceed the range of an `i32`. Panics if even that
+    /// overflows.
+    pub fn map_coord(&self, coord: Coordinate) -> Coordinate<i64> {
+        let shift = |before: &[usize], v: i32| {
+            let idx = v.clamp(0, before.len() as i32 - 1) as usize;
+
+            i64::try_from(before[idx])
+                .ok()
+                .zip(i64::try_from(self.factor - 1).ok())
+                .and_then(|(count, extra)| count.checked_mul(extra))
+                .and_then(|offset| offset.checked_add(v as i64))
+                .expect("Expanded coordinate does not fit into an i64")
+        };
+
+        Coordinate::new(
+            shift(&self.empty_cols_before, coord.x),
+            shift(&self.empty_rows_before, coord.y),
+        )
+    }
+}
+
+impl<T: Clone + PartialEq> Grid2D<T> {
+    /// Determines which rows and columns consist entirely of `empty` and
+    /// returns a map that translates coordinates into a space where each of
+    /// these rows and columns is `factor` rows or columns wide.
+    ///
+    /// Panics if `factor` is 0.
+    #[must_use]
+    pub fn expand_empty(&self, empty: &T, factor: usize) -> ExpansionMap {
+        assert!(factor > 0, "Expansion factor must be at least 1");
+
+        let prefix_counts = |is_empty: Vec<bool>| {
+            let mut counts = vec![0];
+
+            for e in is_empty {
+                counts.push(counts[counts.len() - 1] + e as usize);
+            }
+
+            counts
+        };
+
+        let empty_rows = self
+            .row_iter()
+            .map(|row| row.iter().all(|v| v == empty))
+            .collect();
+
+        let empty_cols = self
+            .col_iter()
+            .map(|col| col.iter().all(|v| v == empty))
+            .collect();
+
+        ExpansionMap {
+            factor,
+            empty_rows_before: prefix_counts(empty_rows),
+            empty_cols_before: prefix_counts(empty_cols),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn galaxy_distances(factor: usize) -> i64 {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ...#......
+            .......#..
+            #.........
+            ..........
+            ......#...
+            .#........
+            .........#
+            ..........
+            .......#..
+            #...#.....
+        "});
+
+        let map = grid.expand_empty(&'.', factor);
+
+        let galaxies = grid
+            .iter()
+            .filter(|(_, c)| **c == '#')
+            .map(|(coord, _)| map.map_coord(coord))
+            .collect::<Vec<_>>();
+
+        let mut sum = 0;
+
+        for i in 0..galaxies.len() {
+            for j in (i + 1)..galaxies.len() {
+                sum += galaxies[i].manhattan_distance(galaxies[j]);
+            }
+        }
+
+        sum
+    }
+
+    #[test]
+    fn test_expand_empty() {
+        assert_eq!(galaxy_distances(1), 292);
+        assert_eq!(galaxy_distances(2), 374);
+        assert_eq!(galaxy_distances(10), 1030);
+        assert_eq!(galaxy_distances(100), 8410);
+        assert_eq!(galaxy_distances(1_000_000), 82_000_210);
+    }
+
+    #[test]
+    fn test_map_coord() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            #..
+            ...
+            ..#
+        "});
+
+        let map = grid.expand_empty(&'.', 5);
+
+        assert_eq!(map.map_coord(Coordinate::new(0, 0)), Coordinate::new(0, 0));
+        assert_eq!(map.map_coord(Coordinate::new(1, 1)), Coordinate::new(1, 1));
+        assert_eq!(map.map_coord(Coordinate::new(2, 2)), Coordinate::new(6, 6));
+        assert_eq!(
+            map.map_coord(Coordinate::new(-1, 7)),
+            Coordinate::new(-1, 11)
+        );
+
+        // Larger than i32::MAX
+        let map = grid.expand_empty(&'.', 3_000_000_000);
+        assert_eq!(
+            map.map_coord(Coordinate::new(2, 2)),
+            Coordinate::new(3_000_000_001, 3_000_000_001)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expanded coordinate does not fit into an i64")]
+    fn test_map_coord_overflow() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            #..
+            ...
+            ..#
+        "});
+
+        let map = grid.expand_empty(&'.', usize::MAX);
+        let _ = map.map_coord(Coordinate::new(2, 2));
+    }
+}
@@ -1,11 +1,40 @@
 use std::{
     fmt::{Debug, Display},
+    hash::Hash,
     ops::{Index, IndexMut},
 };
 
-use ndarray::{concatenate, Array2, ArrayView1, Axis};
+use ndarray::{concatenate, s, Array2, ArrayView1, Axis};
 
-use crate::prelude::Coordinate;
+use crate::{
+    misc::rle,
+    prelude::{bounding_box, Connectivity, Coordinate, Counter, Direction, HashMap},
+};
+
+/// The reasons why `Grid2D::parse_mapped` can fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// The input contains no cells
+    Empty,
+
+    /// The line with the given (zero-based) index is not as long as the first
+    Ragged(usize),
+
+    /// The character is not in the legend, and no default was given
+    Unmapped(char),
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the grid is empty"),
+            Self::Ragged(line) => write!(f, "line {line} has a different length"),
+            Self::Unmapped(c) => write!(f, "character {c:?} is not in the legend"),
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
 
 /// A 2D grid backed by ndarray.
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -64,6 +93,59 @@ impl<T: Clone + From<char>> Grid2D<T> {
             data,
         }
     }
+
+    /// Parses a grid from a string slice whose lines may have different
+    /// lengths.
+    ///
+    /// The grid is as wide as the longest line, and shorter lines are padded
+    /// on the right with `default`.
+    ///
+    /// Unlike `parse`, this does not call `trim()` on the input, because that
+    /// would also strip leading whitespace from the first line and shift it
+    /// relative to the others. Only leading and trailing blank lines (lines
+    /// that are empty or consist only of whitespace) are removed.
+    #[must_use]
+    pub fn parse_sparse(input: &str, default: T) -> Self {
+        let lines = input.lines().collect::<Vec<_>>();
+
+        let first = lines
+            .iter()
+            .position(|line| !line.trim().is_empty())
+            .unwrap_or(lines.len());
+        let last = lines
+            .iter()
+            .rposition(|line| !line.trim().is_empty())
+            .map_or(first, |i| i + 1);
+
+        let lines = &lines[first..last];
+
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = lines.len();
+
+        assert!(width > 0, "Grid width must be greater than 0");
+        assert!(height > 0, "Grid height must be greater than 0");
+
+        let mut elems = Vec::with_capacity(width * height);
+
+        for line in lines {
+            let len = line.chars().count();
+
+            elems.extend(line.chars().map(T::from));
+            elems.extend(std::iter::repeat_n(default.clone(), width - len));
+        }
+
+        let data = Array2::from_shape_vec((height, width), elems).unwrap();
+
+        Self {
+            width: width as i32,
+            height: height as i32,
+            data,
+        }
+    }
 }
 
 impl<T: Clone> Grid2D<T> {
@@ -97,6 +179,75 @@ impl<T: Clone> Grid2D<T> {
         }
     }
 
+    /// Parses a grid from a string slice, mapping each character to a value
+    /// using `legend`.
+    ///
+    /// Characters that are not in the legend become `default`. If no default is
+    /// given, the first unmapped character is returned as the error instead.
+    ///
+    /// Like `parse`, this trims the input. Unlike `parse`, empty input and
+    /// lines of different lengths are reported as errors instead of panicking.
+    pub fn parse_mapped(
+        input: &str,
+        legend: &HashMap<char, T>,
+        default: Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let lines = input.trim().lines().collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        if width == 0 {
+            return Err(ParseGridError::Empty);
+        }
+
+        if let Some(ragged) = lines.iter().position(|line| line.chars().count() != width) {
+            return Err(ParseGridError::Ragged(ragged));
+        }
+
+        let mut data = Vec::with_capacity(width * lines.len());
+
+        for c in lines.iter().flat_map(|line| line.chars()) {
+            match legend.get(&c).or(default.as_ref()) {
+                Some(value) => data.push(value.clone()),
+                None => return Err(ParseGridError::Unmapped(c)),
+            }
+        }
+
+        Ok(Self::from_shape_vec(width, lines.len(), data))
+    }
+
+    /// Plots the given points onto a grid that is just large enough to hold
+    /// them. Cells at one of the points are set to `present`, all others to
+    /// `absent`.
+    ///
+    /// Returns the grid and the coordinate of its top-left corner, which has to
+    /// be subtracted from a point to get its position in the grid.
+    ///
+    /// Panics if there are no points.
+    #[must_use]
+    pub fn from_coordinates(
+        points: impl IntoIterator<Item = Coordinate>,
+        present: T,
+        absent: T,
+    ) -> (Self, Coordinate) {
+        let points = points.into_iter().collect::<Vec<_>>();
+
+        assert!(!points.is_empty(), "Cannot create a grid from zero points");
+
+        let (min, max) = bounding_box(points.iter().copied());
+
+        let mut grid = Self::new(
+            (max.x - min.x + 1) as usize,
+            (max.y - min.y + 1) as usize,
+            absent,
+        );
+
+        for point in points {
+            grid[point - min] = present.clone();
+        }
+
+        (grid, min)
+    }
+
     /// Returns the width of the grid.
     #[must_use]
     pub fn width(&self) -> usize {
@@ -127,6 +278,15 @@ impl<T: Clone> Grid2D<T> {
         coord.x >= 0 && coord.y >= 0 && coord.x < self.width && coord.y < self.height
     }
 
+    /// Clamps the given coordinate so that it lies within the grid
+    #[must_use]
+    pub fn clamp_coord(&self, coord: Coordinate) -> Coordinate {
+        coord.clamp(
+            Coordinate::new(0, 0),
+            Coordinate::new(self.width - 1, self.height - 1),
+        )
+    }
+
     /// Returns the value at the given coordinate. Out-of-bounds accesses return
     /// `None`.
     #[must_use]
@@ -148,6 +308,27 @@ impl<T: Clone> Grid2D<T> {
         self.data.get((y as usize, x as usize)).unwrap()
     }
 
+    /// Treats the grid as one tile of an infinite plane and returns the value
+    /// at the given coordinate of that plane. This is the same as `get_wrap`.
+    #[must_use]
+    pub fn tiled_get(&self, coord: Coordinate) -> &T {
+        self.get_wrap(coord)
+    }
+
+    /// Returns a `width` by `height` window of the infinite tiling of the
+    /// grid (see `tiled_get`), with its top-left corner at `top_left`.
+    #[must_use]
+    pub fn tiled_region(&self, top_left: Coordinate, width: usize, height: usize) -> Self {
+        let data = (0..height as i32)
+            .flat_map(|y| {
+                (0..width as i32).map(move |x| self.tiled_get(top_left + Coordinate::new(x, y)))
+            })
+            .cloned()
+            .collect();
+
+        Self::from_shape_vec(width, height, data)
+    }
+
     /// Returns a mutable reference to the value at the given coordinate,
     /// or `None` if the coordinate is out-of-bounds.
     #[must_use]
@@ -194,6 +375,58 @@ impl<T: Clone> Grid2D<T> {
         }
     }
 
+    /// Scans each row from left to right, similar to `Iterator::scan`.
+    ///
+    /// An accumulator is created with `init` at the start of every row, and `f`
+    /// is called with the accumulator and each cell of the row in turn. The
+    /// value returned by `f` becomes the value of the cell in the new grid.
+    #[must_use]
+    pub fn scan_rows<A, T2: Clone>(
+        &self,
+        init: impl Fn() -> A,
+        mut f: impl FnMut(&mut A, &T) -> T2,
+    ) -> Grid2D<T2> {
+        let mut data = Vec::with_capacity(self.area());
+
+        for row in self.row_iter() {
+            let mut acc = init();
+            data.extend(row.iter().map(|value| f(&mut acc, value)));
+        }
+
+        Grid2D::from_shape_vec(self.width(), self.height(), data)
+    }
+
+    /// Scans each column from top to bottom, similar to `Iterator::scan`.
+    ///
+    /// This works like `scan_rows`, except that the accumulator is reset at the
+    /// top of every column.
+    #[must_use]
+    pub fn scan_columns<A, T2: Clone>(
+        &self,
+        init: impl Fn() -> A,
+        mut f: impl FnMut(&mut A, &T) -> T2,
+    ) -> Grid2D<T2> {
+        let mut data = Vec::with_capacity(self.area());
+
+        for col in self.col_iter() {
+            let mut acc = init();
+            data.extend(col.iter().map(|value| f(&mut acc, value)));
+        }
+
+        let mut result = Grid2D::from_shape_vec(self.height(), self.width(), data);
+        result.transpose();
+        result
+    }
+
+    /// Returns a grid of the same shape in which each cell is `true` if the sum
+    /// of its coordinates is even, like the black squares of a checkerboard.
+    #[must_use]
+    pub fn parity(&self) -> Grid2D<bool> {
+        let data = self.iter().map(|(c, _)| (c.x + c.y) % 2 == 0).collect();
+
+        Grid2D::from_shape_vec(self.width(), self.height(), data)
+    }
+
     /// Returns a new, larger grid that contains the original grid. The
     /// new grid is padded with the given value.
     #[must_use]
@@ -213,6 +446,84 @@ impl<T: Clone> Grid2D<T> {
         grid
     }
 
+    /// Copies the cells of `other` into `self`, placing the top-left corner of
+    /// `other` at `top_left`. Cells that fall outside of `self` are clipped.
+    pub fn blit(&mut self, top_left: Coordinate, other: &Grid2D<T>) {
+        self.blit_with(top_left, other, |_, new| new.clone());
+    }
+
+    /// Like [`Grid2D::blit`], but combines overlapping cells using `f`, which
+    /// is called with the existing value and the value from `other`.
+    pub fn blit_with(&mut self, top_left: Coordinate, other: &Grid2D<T>, f: impl Fn(&T, &T) -> T) {
+        for (coord, value) in other.iter() {
+            if let Some(cell) = self.get_mut(top_left + coord) {
+                *cell = f(cell, value);
+            }
+        }
+    }
+
+    /// Returns the grid's cells as a flat slice in row-major order, i.e. in
+    /// the same order as `iter()`.
+    ///
+    /// Transposing or flipping the grid only changes how the backing array is
+    /// indexed, so afterwards the cells are no longer stored in row-major
+    /// order and `None` is returned. Call `make_contiguous` first to copy them
+    /// back into row-major order.
+    #[must_use]
+    pub fn as_slice(&self) -> Option<&[T]> {
+        self.data.as_slice()
+    }
+
+    /// Copies the cells back into row-major order if a transformation (e.g.
+    /// `transpose`) left them in a different order, so that `as_slice`
+    /// succeeds afterwards. This takes O(n) time if a copy is needed.
+    pub fn make_contiguous(&mut self) {
+        if !self.data.is_standard_layout() {
+            self.data = self.data.as_standard_layout().into_owned();
+        }
+    }
+
+    /// Returns the row-major index of the given coordinate (the position of
+    /// the cell in `iter()` and `as_slice()`), or `None` if the coordinate is
+    /// outside of the grid.
+    #[must_use]
+    pub fn index_of(&self, coord: Coordinate) -> Option<usize> {
+        if !self.contains_coord(coord) {
+            return None;
+        }
+
+        Some((coord.y * self.width + coord.x) as usize)
+    }
+
+    /// Returns the coordinate of the cell at the given row-major index (the
+    /// position of the cell in `iter()` and `as_slice()`).
+    ///
+    /// Panics if the index is out of bounds.
+    #[must_use]
+    pub fn coord_of(&self, index: usize) -> Coordinate {
+        assert!(index < self.area(), "Index out of bounds");
+
+        let width = self.width();
+
+        Coordinate::new((index % width) as i32, (index / width) as i32)
+    }
+
+    /// Sets every cell on the line from `from` to `to` (both inclusive) to
+    /// `value`. Cells outside of the grid are skipped.
+    pub fn draw_line(&mut self, from: Coordinate, to: Coordinate, value: T) {
+        self.draw_line_with(from, to, |_| value.clone());
+    }
+
+    /// Like [`Grid2D::draw_line`], but replaces the value of each cell on the
+    /// line with the result of calling `f` on it.
+    pub fn draw_line_with(&mut self, from: Coordinate, to: Coordinate, mut f: impl FnMut(&T) -> T) {
+        for coord in from.line_to(to) {
+            if let Some(cell) = self.get_mut(coord) {
+                *cell = f(cell);
+            }
+        }
+    }
+
     /// Returns an iterator over the grid's elements and their coordinates.
     pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
         self.data
@@ -220,6 +531,179 @@ impl<T: Clone> Grid2D<T> {
             .map(|((y, x), value)| (Coordinate::new(x as i32, y as i32), value))
     }
 
+    /// Returns an iterator over mutable references to the grid's elements and
+    /// their coordinates, in the same order as `iter()`.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coordinate, &mut T)> + '_ {
+        self.data
+            .indexed_iter_mut()
+            .map(|((y, x), value)| (Coordinate::new(x as i32, y as i32), value))
+    }
+
+    /// Returns an iterator over the in-bounds neighbors of the given coordinate
+    /// and their values.
+    pub fn neighbors_of(
+        &self,
+        coord: Coordinate,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        connectivity.directions().filter_map(move |dir| {
+            let neighbor = coord + dir;
+            self.get(neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    /// Returns the four cardinal neighbors of the given coordinate and their
+    /// values, or `None` for neighbors outside of the grid.
+    ///
+    /// The array is in `Direction::cardinal()` order, so it can be indexed with
+    /// `usize::from(direction)`. This avoids the iterator overhead of
+    /// `neighbors_of` in tight loops.
+    #[must_use]
+    pub fn cardinal_neighbors(&self, coord: Coordinate) -> [Option<(Coordinate, &T)>; 4] {
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+        .map(|dir| {
+            let neighbor = coord + dir;
+            self.get(neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    /// Returns an iterator over the in-bounds neighbors of the given coordinate
+    /// for which `passable` (called with the neighbor's coordinate and value)
+    /// returns true.
+    pub fn passable_neighbors<'a>(
+        &'a self,
+        coord: Coordinate,
+        connectivity: Connectivity,
+        passable: impl Fn(Coordinate, &T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coordinate> + 'a {
+        self.neighbors_of(coord, connectivity)
+            .filter(move |&(neighbor, value)| passable(neighbor, value))
+            .map(|(neighbor, _)| neighbor)
+    }
+
+    /// Maps the grid to a new grid with the same dimensions, calling `f` with
+    /// each cell and the values of its in-bounds neighbors.
+    #[must_use]
+    pub fn map_neighborhood<T2: Clone>(
+        &self,
+        connectivity: Connectivity,
+        f: impl Fn(&T, &[&T]) -> T2,
+    ) -> Grid2D<T2> {
+        let mut neighbors = Vec::with_capacity(8);
+
+        let data = self
+            .iter()
+            .map(|(coord, value)| {
+                neighbors.clear();
+                neighbors.extend(self.neighbors_of(coord, connectivity).map(|(_, n)| n));
+                f(value, &neighbors)
+            })
+            .collect();
+
+        Grid2D::from_shape_vec(self.width(), self.height(), data)
+    }
+
+    /// Returns a grid of the same shape in which each cell holds the number of
+    /// its neighbors that satisfy `predicate`. Neighbors outside of the grid
+    /// are not counted.
+    #[must_use]
+    pub fn neighbor_count_grid(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> Grid2D<u8> {
+        let data = self
+            .iter()
+            .map(|(coord, _)| {
+                self.neighbors_of(coord, connectivity)
+                    .filter(|(_, value)| predicate(value))
+                    .count() as u8
+            })
+            .collect();
+
+        Grid2D::from_shape_vec(self.width(), self.height(), data)
+    }
+
+    /// Like [`Grid2D::neighbor_count_grid`], but neighbors wrap around the
+    /// edges of the grid.
+    #[must_use]
+    pub fn neighbor_count_grid_wrap(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> Grid2D<u8> {
+        let data = self
+            .iter()
+            .map(|(coord, _)| {
+                connectivity
+                    .directions()
+                    .filter(|&dir| predicate(self.get_wrap(coord + dir)))
+                    .count() as u8
+            })
+            .collect();
+
+        Grid2D::from_shape_vec(self.width(), self.height(), data)
+    }
+
+    /// Returns an iterator over the cells along a straight line starting at
+    /// (but not including) `from` and going in direction `dir` until the line
+    /// leaves the grid.
+    pub fn ray(
+        &self,
+        from: Coordinate,
+        dir: Direction,
+    ) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        std::iter::successors(Some(from + dir), move |&coord| Some(coord + dir))
+            .map_while(move |coord| self.get(coord).map(|value| (coord, value)))
+    }
+
+    /// Like `ray`, but wraps around the edges of the grid.
+    ///
+    /// The coordinates are wrapped back into the grid, and the iterator stops
+    /// after `area()` steps so that it can't loop forever.
+    pub fn ray_wrap(
+        &self,
+        from: Coordinate,
+        dir: Direction,
+    ) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        std::iter::successors(Some(from + dir), move |&coord| Some(coord + dir))
+            .take(self.area())
+            .map(move |coord| {
+                let wrapped = Coordinate::new(
+                    coord.x.rem_euclid(self.width),
+                    coord.y.rem_euclid(self.height),
+                );
+
+                (wrapped, self.get_wrap(wrapped))
+            })
+    }
+
+    /// Returns an iterator over the coordinates on the border of the grid.
+    ///
+    /// The coordinates are yielded in row-major order, so each corner is only
+    /// yielded once.
+    pub fn border_coords(&self) -> impl Iterator<Item = Coordinate> {
+        let width = self.width;
+        let height = self.height;
+
+        (0..height).flat_map(move |y| {
+            let step = if y == 0 || y == height - 1 {
+                1
+            } else {
+                (width - 1).max(1)
+            };
+
+            (0..width)
+                .step_by(step as usize)
+                .map(move |x| Coordinate::new(x, y))
+        })
+    }
+
     /// Returns an iterator over the grid's rows
     pub fn row_iter(&self) -> impl Iterator<Item = ArrayView1<T>> + '_ {
         self.data.axis_iter(ndarray::Axis(0))
@@ -230,6 +714,17 @@ impl<T: Clone> Grid2D<T> {
         self.data.axis_iter(ndarray::Axis(1))
     }
 
+    /// Returns the run-length encoding of row `y`.
+    ///
+    /// Panics if `y` is out of bounds.
+    #[must_use]
+    pub fn row_rle(&self, y: usize) -> Vec<(T, usize)>
+    where
+        T: PartialEq,
+    {
+        rle::encode_iter(self.data.row(y))
+    }
+
     /// Returns all diagonals of the grid as Vec<Vec<T>> going from top-right to
     /// bottom-left and starting with the top-left corner..
     #[must_use]
@@ -291,6 +786,68 @@ impl<T: Clone> Grid2D<T> {
     }
 }
 
+impl<T: Clone + PartialEq> Grid2D<T> {
+    /// Replaces every occurrence of `old` with `new` and returns the number of
+    /// cells that were replaced.
+    pub fn replace(&mut self, old: &T, new: T) -> usize {
+        let mut replaced = 0;
+
+        for value in self.data.iter_mut() {
+            if value == old {
+                *value = new.clone();
+                replaced += 1;
+            }
+        }
+
+        replaced
+    }
+
+    /// Removes all rows and columns that consist entirely of `value` from the
+    /// edges of the grid. This is the inverse of `pad`.
+    ///
+    /// Returns `None` if every cell is equal to `value`.
+    #[must_use]
+    pub fn trim(&self, value: &T) -> Option<Self> {
+        if self.data.iter().all(|v| v == value) {
+            return None;
+        }
+
+        let (min, max) = bounding_box(
+            self.iter()
+                .filter(|(_, v)| *v != value)
+                .map(|(coord, _)| coord),
+        );
+
+        let data = self.data.slice(s![min.y..=max.y, min.x..=max.x]).to_owned();
+
+        Some(Self {
+            width: max.x - min.x + 1,
+            height: max.y - min.y + 1,
+            data,
+        })
+    }
+}
+
+impl<T: Clone + Hash + Eq> Grid2D<T> {
+    /// Counts how often each value occurs in the grid.
+    #[must_use]
+    pub fn value_histogram(&self) -> Counter<&T> {
+        self.data.iter().collect()
+    }
+
+    /// Returns the most frequent value in the grid (e.g. the background color).
+    ///
+    /// If several values are equally frequent, the one that occurs first in
+    /// row-major order wins.
+    #[must_use]
+    pub fn most_common_value(&self) -> Option<&T> {
+        let histogram = self.value_histogram();
+        let max = histogram.values().max()?;
+
+        self.data.iter().find(|value| histogram[value] == *max)
+    }
+}
+
 impl<T: Clone> Index<Coordinate> for Grid2D<T> {
     type Output = T;
 
@@ -407,6 +964,49 @@ mod tests {
         assert_eq!(grid.get_wrap_mut(Coordinate::new(0, 3)), &mut 1);
     }
 
+    #[test]
+    fn clamp_coord_test() {
+        let grid: Grid2D<i32> = Grid2D::new(4, 3, 0);
+
+        assert_eq!(
+            grid.clamp_coord(Coordinate::new(1, 1)),
+            Coordinate::new(1, 1)
+        );
+        assert_eq!(
+            grid.clamp_coord(Coordinate::new(-1, 1)),
+            Coordinate::new(0, 1)
+        );
+        assert_eq!(
+            grid.clamp_coord(Coordinate::new(7, -3)),
+            Coordinate::new(3, 0)
+        );
+        assert_eq!(
+            grid.clamp_coord(Coordinate::new(2, 3)),
+            Coordinate::new(2, 2)
+        );
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            O.#
+            .O.
+            #.O
+        "});
+
+        assert_eq!(grid.replace(&'O', '#'), 3);
+        assert_eq!(grid.replace(&'O', '#'), 0);
+
+        assert_eq!(
+            grid,
+            Grid2D::parse(indoc! {"
+                #.#
+                .#.
+                #.#
+            "})
+        );
+    }
+
     #[test]
     fn test_transpose() {
         let input = indoc! {"
@@ -536,6 +1136,476 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_parse_sparse() {
+        let grid: Grid2D<char> = Grid2D::parse_sparse("\n  #\n###.\n#\n\n", ' ');
+
+        assert_eq!(grid.width(), 4);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(
+            grid,
+            Grid2D::from(vec![
+                vec![' ', ' ', '#', ' '],
+                vec!['#', '#', '#', '.'],
+                vec!['#', ' ', ' ', ' '],
+            ])
+        );
+
+        // Whitespace-only lines are blank, too, but only at the edges
+        let grid: Grid2D<char> = Grid2D::parse_sparse("   \n .\n  \n#\n \t\n", '?');
+
+        assert_eq!(
+            grid,
+            Grid2D::from(vec![vec![' ', '.'], vec![' ', ' '], vec!['#', '?']])
+        );
+    }
+
+    #[test]
+    fn test_parse_mapped() {
+        let legend: HashMap<char, u8> = [('.', 0), ('#', 1), ('O', 2)].into_iter().collect();
+
+        let grid = Grid2D::parse_mapped("#.O\n.#.\n", &legend, None).unwrap();
+        assert_eq!(grid, Grid2D::from_shape_vec(3, 2, vec![1, 0, 2, 0, 1, 0]));
+
+        assert_eq!(
+            Grid2D::parse_mapped("#.\n.S", &legend, None),
+            Err(ParseGridError::Unmapped('S'))
+        );
+
+        let grid = Grid2D::parse_mapped("#.\n.S", &legend, Some(9)).unwrap();
+        assert_eq!(grid, Grid2D::from_shape_vec(2, 2, vec![1, 0, 0, 9]));
+
+        assert_eq!(
+            Grid2D::parse_mapped(" \n\n", &legend, Some(9)),
+            Err(ParseGridError::Empty)
+        );
+        assert_eq!(
+            Grid2D::parse_mapped("#.\n.#.\n..", &legend, None),
+            Err(ParseGridError::Ragged(1))
+        );
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let mut grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            abc
+            def
+        "});
+
+        assert_eq!(grid.as_slice(), Some(&['a', 'b', 'c', 'd', 'e', 'f'][..]));
+
+        grid.transpose();
+        assert_eq!(grid.as_slice(), None);
+
+        grid.make_contiguous();
+        assert_eq!(grid.as_slice(), Some(&['a', 'd', 'b', 'e', 'c', 'f'][..]));
+
+        grid.flip_x();
+        assert_eq!(grid.as_slice(), None);
+
+        grid.make_contiguous();
+        assert!(grid
+            .as_slice()
+            .unwrap()
+            .iter()
+            .eq(grid.iter().map(|(_, c)| c)));
+    }
+
+    #[test]
+    fn test_index_of_and_coord_of() {
+        let grid: Grid2D<char> = Grid2D::new(3, 2, '.');
+
+        for (i, (coord, _)) in grid.iter().enumerate() {
+            assert_eq!(grid.index_of(coord), Some(i));
+            assert_eq!(grid.coord_of(i), coord);
+        }
+
+        assert_eq!(grid.index_of(Coordinate::new(3, 0)), None);
+        assert_eq!(grid.index_of(Coordinate::new(0, -1)), None);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut grid: Grid2D<i32> = Grid2D::new(3, 3, 1);
+
+        for (coord, value) in grid.iter_mut() {
+            if coord.x == coord.y {
+                *value = 0;
+            }
+        }
+
+        assert_eq!(
+            grid,
+            Grid2D::from_shape_vec(3, 3, vec![0, 1, 1, 1, 0, 1, 1, 1, 0])
+        );
+
+        let order = grid.iter().map(|(c, _)| c).collect::<Vec<_>>();
+        assert!(grid.iter_mut().map(|(c, _)| c).eq(order));
+    }
+
+    #[test]
+    fn test_from_coordinates() {
+        let points = [
+            Coordinate::new(5, -1),
+            Coordinate::new(3, 0),
+            Coordinate::new(4, 0),
+            Coordinate::new(5, 0),
+        ];
+
+        let (grid, origin) = Grid2D::from_coordinates(points, '#', '.');
+
+        assert_eq!(origin, Coordinate::new(3, -1));
+        assert_eq!(
+            grid,
+            Grid2D::parse(indoc! {"
+                ..#
+                ###
+            "})
+        );
+    }
+
+    #[test]
+    fn test_most_common_value() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ..#
+            #.o
+        "});
+
+        let histogram = grid.value_histogram();
+
+        assert_eq!(histogram[&'.'], 3);
+        assert_eq!(histogram[&'#'], 2);
+        assert_eq!(histogram[&'o'], 1);
+        assert_eq!(grid.most_common_value(), Some(&'.'));
+
+        // Ties are broken by the first occurrence in row-major order
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ba
+            ab
+        "});
+
+        assert_eq!(grid.most_common_value(), Some(&'b'));
+    }
+
+    #[test]
+    fn test_trim() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            .....
+            ..#..
+            .#.#.
+            .....
+        "});
+
+        assert_eq!(
+            grid.trim(&'.'),
+            Some(Grid2D::parse(indoc! {"
+                .#.
+                #.#
+            "}))
+        );
+
+        assert_eq!(grid.pad(2, '.').trim(&'.'), grid.trim(&'.'));
+        assert_eq!(Grid2D::new(3, 3, '.').trim(&'.'), None);
+    }
+
+    #[test]
+    fn test_tiled_region() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ab
+            cd
+        "});
+
+        assert_eq!(grid.tiled_get(Coordinate::new(-1, 5)), &'d');
+
+        assert_eq!(
+            grid.tiled_region(Coordinate::new(-1, -1), 5, 3),
+            Grid2D::parse(indoc! {"
+                dcdcd
+                babab
+                dcdcd
+            "})
+        );
+
+        assert_eq!(grid.tiled_region(Coordinate::new(4, 2), 2, 2), grid);
+    }
+
+    #[test]
+    fn test_draw_line() {
+        let mut grid: Grid2D<char> = Grid2D::new(4, 3, '.');
+
+        grid.draw_line(Coordinate::new(0, 0), Coordinate::new(3, 0), '#');
+        grid.draw_line(Coordinate::new(-1, -1), Coordinate::new(2, 2), 'o');
+
+        assert_eq!(
+            grid,
+            Grid2D::parse(indoc! {"
+                o###
+                .o..
+                ..o.
+            "})
+        );
+    }
+
+    #[test]
+    fn test_draw_line_with() {
+        let mut grid: Grid2D<u8> = Grid2D::new(3, 3, 0);
+
+        grid.draw_line_with(Coordinate::new(0, 1), Coordinate::new(2, 1), |c| c + 1);
+        grid.draw_line_with(Coordinate::new(1, 0), Coordinate::new(1, 2), |c| c + 1);
+
+        assert_eq!(
+            grid,
+            Grid2D::from_shape_vec(3, 3, vec![0, 1, 0, 1, 2, 1, 0, 1, 0])
+        );
+    }
+
+    #[test]
+    fn test_parity() {
+        let grid: Grid2D<char> = Grid2D::new(3, 2, '.');
+
+        assert_eq!(
+            grid.parity(),
+            Grid2D::from_shape_vec(3, 2, vec![true, false, true, false, true, false])
+        );
+    }
+
+    #[test]
+    fn test_neighbor_count_grid() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            #..
+            .#.
+            ...
+        "});
+
+        assert_eq!(
+            grid.neighbor_count_grid(|c| *c == '#', Connectivity::Moore),
+            Grid2D::from_shape_vec(3, 3, vec![1, 2, 1, 2, 1, 1, 1, 1, 1])
+        );
+
+        assert_eq!(
+            grid.neighbor_count_grid(|c| *c == '#', Connectivity::VonNeumann),
+            Grid2D::from_shape_vec(3, 3, vec![0, 2, 0, 2, 0, 1, 0, 1, 0])
+        );
+    }
+
+    #[test]
+    fn test_neighbor_count_grid_wrap() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            #..
+            ...
+            ...
+        "});
+
+        assert_eq!(
+            grid.neighbor_count_grid_wrap(|c| *c == '#', Connectivity::Moore),
+            Grid2D::from_shape_vec(3, 3, vec![0, 1, 1, 1, 1, 1, 1, 1, 1])
+        );
+    }
+
+    #[test]
+    fn test_blit() {
+        let mut grid: Grid2D<char> = Grid2D::new(4, 3, '.');
+        let sprite: Grid2D<char> = Grid2D::parse(indoc! {"
+            ab
+            cd
+        "});
+
+        grid.blit(Coordinate::new(1, 0), &sprite);
+        grid.blit(Coordinate::new(3, 2), &sprite);
+        grid.blit(Coordinate::new(-1, 1), &sprite);
+
+        assert_eq!(
+            grid,
+            Grid2D::parse(indoc! {"
+                .ab.
+                bcd.
+                d..a
+            "})
+        );
+    }
+
+    #[test]
+    fn test_blit_with() {
+        let mut grid: Grid2D<u8> = Grid2D::new(3, 3, 1);
+        let stamp: Grid2D<u8> = Grid2D::new(2, 2, 10);
+
+        grid.blit_with(Coordinate::new(0, 0), &stamp, |a, b| a + b);
+        grid.blit_with(Coordinate::new(1, 1), &stamp, |a, b| a + b);
+
+        assert_eq!(
+            grid,
+            Grid2D::from_shape_vec(3, 3, vec![11, 11, 1, 11, 21, 11, 1, 11, 11])
+        );
+    }
+
+    #[test]
+    fn test_row_rle() {
+        let mut grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ..##.
+            #####
+        "});
+
+        assert_eq!(grid.row_rle(0), vec![('.', 2), ('#', 2), ('.', 1)]);
+        assert_eq!(grid.row_rle(1), vec![('#', 5)]);
+
+        grid.transpose();
+
+        assert_eq!(grid.row_rle(4), vec![('.', 1), ('#', 1)]);
+    }
+
+    #[test]
+    fn test_neighbors_of() {
+        let grid: Grid2D<i32> = Grid2D::from_shape_vec(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        assert_eq!(
+            grid.neighbors_of(Coordinate::new(0, 0), Connectivity::VonNeumann)
+                .collect::<Vec<_>>(),
+            vec![(Coordinate::new(1, 0), &2), (Coordinate::new(0, 1), &4)]
+        );
+
+        assert_eq!(
+            grid.neighbors_of(Coordinate::new(1, 1), Connectivity::Moore)
+                .count(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_cardinal_neighbors() {
+        let grid: Grid2D<i32> = Grid2D::from_shape_vec(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let neighbors = grid.cardinal_neighbors(Coordinate::new(0, 1));
+
+        assert_eq!(
+            neighbors[usize::from(Direction::Up)],
+            Some((Coordinate::new(0, 0), &1))
+        );
+        assert_eq!(
+            neighbors[usize::from(Direction::Right)],
+            Some((Coordinate::new(1, 1), &5))
+        );
+        assert_eq!(
+            neighbors[usize::from(Direction::Down)],
+            Some((Coordinate::new(0, 2), &7))
+        );
+        assert_eq!(neighbors[usize::from(Direction::Left)], None);
+
+        let center = grid.cardinal_neighbors(Coordinate::new(1, 1));
+
+        assert!(center
+            .iter()
+            .flatten()
+            .copied()
+            .eq(grid.neighbors_of(Coordinate::new(1, 1), Connectivity::VonNeumann)));
+    }
+
+    #[test]
+    fn test_passable_neighbors() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            .#.
+            ...
+            ##.
+        "});
+
+        let open = |_, c: &char| *c == '.';
+
+        assert_eq!(
+            grid.passable_neighbors(Coordinate::new(1, 1), Connectivity::VonNeumann, open)
+                .collect::<Vec<_>>(),
+            vec![Coordinate::new(2, 1), Coordinate::new(0, 1)]
+        );
+
+        // The coordinate is passed to the predicate as well
+        assert_eq!(
+            grid.passable_neighbors(Coordinate::new(1, 1), Connectivity::Moore, |coord, c| {
+                *c == '.' && coord.y == 0
+            })
+            .collect::<Vec<_>>(),
+            vec![Coordinate::new(0, 0), Coordinate::new(2, 0)]
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid: Grid2D<i32> = Grid2D::from_shape_vec(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        assert_eq!(
+            grid.ray(Coordinate::new(0, 1), Direction::Right)
+                .collect::<Vec<_>>(),
+            vec![(Coordinate::new(1, 1), &5), (Coordinate::new(2, 1), &6)]
+        );
+
+        assert_eq!(
+            grid.ray(Coordinate::new(0, 0), Direction::DownRight)
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>(),
+            vec![5, 9]
+        );
+
+        assert_eq!(grid.ray(Coordinate::new(0, 0), Direction::Up).count(), 0);
+
+        // Stop at the first obstacle
+        assert_eq!(
+            grid.ray(Coordinate::new(2, 2), Direction::Left)
+                .find(|(_, v)| **v < 8),
+            Some((Coordinate::new(0, 2), &7))
+        );
+    }
+
+    #[test]
+    fn test_ray_wrap() {
+        let grid: Grid2D<i32> = Grid2D::from_shape_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(
+            grid.ray_wrap(Coordinate::new(1, 0), Direction::Right)
+                .collect::<Vec<_>>(),
+            vec![
+                (Coordinate::new(2, 0), &3),
+                (Coordinate::new(0, 0), &1),
+                (Coordinate::new(1, 0), &2),
+                (Coordinate::new(2, 0), &3),
+                (Coordinate::new(0, 0), &1),
+                (Coordinate::new(1, 0), &2),
+            ]
+        );
+
+        assert_eq!(
+            grid.ray_wrap(Coordinate::new(0, 0), Direction::Up)
+                .map(|(_, v)| *v)
+                .take(3)
+                .collect::<Vec<_>>(),
+            vec![4, 1, 4]
+        );
+    }
+
+    #[test]
+    fn test_border_coords() {
+        let grid: Grid2D<i32> = Grid2D::new(3, 3, 0);
+
+        assert_eq!(
+            grid.border_coords().collect::<Vec<_>>(),
+            vec![
+                Coordinate::new(0, 0),
+                Coordinate::new(1, 0),
+                Coordinate::new(2, 0),
+                Coordinate::new(0, 1),
+                Coordinate::new(2, 1),
+                Coordinate::new(0, 2),
+                Coordinate::new(1, 2),
+                Coordinate::new(2, 2),
+            ]
+        );
+
+        let grid: Grid2D<i32> = Grid2D::new(4, 5, 0);
+        assert_eq!(grid.border_coords().count(), 14);
+
+        let grid: Grid2D<i32> = Grid2D::new(1, 3, 0);
+        assert_eq!(grid.border_coords().count(), 3);
+
+        let grid: Grid2D<i32> = Grid2D::new(3, 1, 0);
+        assert_eq!(grid.border_coords().count(), 3);
+    }
+
     #[test]
     fn test_row_iter() {
         let grid: Grid2D<i32> = Grid2D::from_shape_vec(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
@@ -584,6 +1654,44 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_scan_rows() {
+        let grid: Grid2D<i32> = Grid2D::from_shape_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+        let running_sum = grid.scan_rows(
+            || 0,
+            |acc, value| {
+                *acc += value;
+                *acc
+            },
+        );
+
+        assert_eq!(
+            running_sum,
+            Grid2D::from_shape_vec(3, 2, vec![1, 3, 6, 4, 9, 15])
+        );
+    }
+
+    #[test]
+    fn test_scan_columns() {
+        let grid: Grid2D<i32> = Grid2D::from_shape_vec(3, 2, vec![3, 1, 4, 1, 5, 9]);
+
+        // Is the cell taller than every cell above it?
+        let visible = grid.scan_columns(
+            || -1,
+            |tallest, value| {
+                let visible = *value > *tallest;
+                *tallest = (*tallest).max(*value);
+                visible
+            },
+        );
+
+        assert_eq!(
+            visible,
+            Grid2D::from_shape_vec(3, 2, vec![true, true, true, false, true, true])
+        );
+    }
+
     #[test]
     fn test_display() {
         let grid: Grid2D<i32> = Grid2D::from_shape_vec(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
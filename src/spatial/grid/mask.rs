@@ -0,0 +1,91 @@
+use ndarray::Zip;
+
+use crate::prelude::Grid2D;
+
+impl Grid2D<bool> {
+    /// Returns the cell-wise logical AND of the two grids.
+    ///
+    /// Panics if the grids have different dimensions.
+    #[must_use]
+    pub fn and(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Returns the cell-wise logical OR of the two grids.
+    ///
+    /// Panics if the grids have different dimensions.
+    #[must_use]
+    pub fn or(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Returns the cell-wise logical XOR of the two grids.
+    ///
+    /// Panics if the grids have different dimensions.
+    #[must_use]
+    pub fn xor(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    /// Returns the cell-wise logical NOT of the grid.
+    #[must_use]
+    pub fn not(&self) -> Self {
+        self.map(|a| !a)
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(bool, bool) -> bool) -> Self {
+        assert!(
+            self.dims() == other.dims(),
+            "Cannot combine grids of different dimensions ({}x{} and {}x{})",
+            self.width,
+            self.height,
+            other.width,
+            other.height
+        );
+
+        Self {
+            width: self.width,
+            height: self.height,
+            data: Zip::from(&self.data)
+                .and(&other.data)
+                .map_collect(|&a, &b| f(a, b)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_ops() {
+        let a = Grid2D::from_shape_vec(2, 2, vec![true, true, false, false]);
+        let b = Grid2D::from_shape_vec(2, 2, vec![true, false, true, false]);
+
+        assert_eq!(
+            a.and(&b),
+            Grid2D::from_shape_vec(2, 2, vec![true, false, false, false])
+        );
+        assert_eq!(
+            a.or(&b),
+            Grid2D::from_shape_vec(2, 2, vec![true, true, true, false])
+        );
+        assert_eq!(
+            a.xor(&b),
+            Grid2D::from_shape_vec(2, 2, vec![false, true, true, false])
+        );
+        assert_eq!(
+            a.not(),
+            Grid2D::from_shape_vec(2, 2, vec![false, false, true, true])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot combine grids of different dimensions")]
+    fn test_mask_dimension_mismatch() {
+        let a = Grid2D::new(2, 3, true);
+        let b = Grid2D::new(3, 2, true);
+
+        let _ = a.and(&b);
+    }
+}
@@ -1,7 +1,24 @@
+mod adjacency;
+mod distance_transform;
+mod expansion;
 mod grid2d;
+mod mask;
+mod morphology;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod pathfinding;
+mod reflection;
+mod regions;
 mod save_to_image;
+#[cfg(feature = "serde")]
+mod serialization;
+mod tilt;
 mod transformations;
+mod visited_grid;
 mod wrappers;
 
+pub use distance_transform::*;
+pub use expansion::*;
 pub use grid2d::*;
+pub use visited_grid::*;
 pub use wrappers::*;
@@ -0,0 +1,120 @@
+use crate::prelude::{Connectivity, Grid2D};
+
+impl<T: Clone + PartialEq> Grid2D<T> {
+    /// Grows the foreground: every `background` cell that has a `foreground`
+    /// neighbor becomes `foreground`. Other cells are left as they are.
+    #[must_use]
+    pub fn dilate(&self, foreground: &T, background: T, connectivity: Connectivity) -> Self {
+        self.map_neighborhood(connectivity, |value, neighbors| {
+            if *value == background && neighbors.contains(&foreground) {
+                foreground.clone()
+            } else {
+                value.clone()
+            }
+        })
+    }
+
+    /// Shrinks the foreground: every `foreground` cell that has a `background`
+    /// neighbor becomes `background`. Other cells are left as they are.
+    ///
+    /// The area outside of the grid does not count as background, so cells on
+    /// the border are not eroded by it.
+    #[must_use]
+    pub fn erode(&self, foreground: &T, background: T, connectivity: Connectivity) -> Self {
+        self.map_neighborhood(connectivity, |value, neighbors| {
+            if value == foreground && neighbors.contains(&&background) {
+                background.clone()
+            } else {
+                value.clone()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_dilate() {
+        let grid: Grid2D<char> = indoc! {"
+            .....
+            .....
+            ..#..
+            .....
+            ....x
+        "}
+        .into();
+
+        let expected: Grid2D<char> = indoc! {"
+            .....
+            ..#..
+            .###.
+            ..#..
+            ....x
+        "}
+        .into();
+
+        assert_eq!(grid.dilate(&'#', '.', Connectivity::VonNeumann), expected);
+
+        let expected: Grid2D<char> = indoc! {"
+            .....
+            .###.
+            .###.
+            .###.
+            ....x
+        "}
+        .into();
+
+        assert_eq!(grid.dilate(&'#', '.', Connectivity::Moore), expected);
+    }
+
+    #[test]
+    fn test_erode() {
+        let grid: Grid2D<char> = indoc! {"
+            #####
+            #####
+            ##.##
+            #####
+            #####
+        "}
+        .into();
+
+        let expected: Grid2D<char> = indoc! {"
+            #####
+            ##.##
+            #...#
+            ##.##
+            #####
+        "}
+        .into();
+
+        assert_eq!(grid.erode(&'#', '.', Connectivity::VonNeumann), expected);
+
+        let expected: Grid2D<char> = indoc! {"
+            #####
+            #...#
+            #...#
+            #...#
+            #####
+        "}
+        .into();
+
+        assert_eq!(grid.erode(&'#', '.', Connectivity::Moore), expected);
+    }
+
+    #[test]
+    fn test_map_neighborhood() {
+        let grid = Grid2D::from_shape_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+        let sums = grid.map_neighborhood(Connectivity::VonNeumann, |v, n| {
+            *v + n.iter().copied().sum::<i32>()
+        });
+
+        assert_eq!(
+            sums,
+            Grid2D::from_shape_vec(3, 2, vec![7, 11, 11, 10, 17, 14])
+        );
+    }
+}
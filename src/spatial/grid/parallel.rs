@@ -0,0 +1,66 @@
+use ndarray::Zip;
+use rayon::prelude::*;
+
+use crate::prelude::{Coordinate, Grid2D};
+
+impl<T: Clone + Sync> Grid2D<T> {
+    /// Like `map`, but applies `f` to the cells in parallel using rayon.
+    ///
+    /// This is only worthwhile if the per-cell work is expensive. The order in
+    /// which `f` is called is unspecified, so it should not rely on side
+    /// effects happening in any particular order.
+    ///
+    /// Requires the `rayon` feature.
+    #[must_use]
+    pub fn par_map<T2: Clone + Send + Sync>(
+        &self,
+        f: impl Fn(&T) -> T2 + Send + Sync,
+    ) -> Grid2D<T2> {
+        Grid2D {
+            width: self.width,
+            height: self.height,
+            data: Zip::from(&self.data).par_map_collect(f),
+        }
+    }
+
+    /// Returns a parallel iterator over the grid's elements and their
+    /// coordinates.
+    ///
+    /// Requires the `rayon` feature.
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (Coordinate, &T)> + '_ {
+        (0..self.area()).into_par_iter().map(move |i| {
+            let coord = self.coord_of(i);
+            (coord, &self[coord])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_map() {
+        let mut grid: Grid2D<u32> = Grid2D::from_shape_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(grid.par_map(|x| x * 10), grid.map(|x| x * 10));
+
+        grid.transpose();
+        assert_eq!(grid.par_map(|x| x * 10), grid.map(|x| x * 10));
+
+        grid.flip_x();
+        assert_eq!(grid.par_map(|x| x * 10), grid.map(|x| x * 10));
+    }
+
+    #[test]
+    fn test_par_iter() {
+        let mut grid: Grid2D<u32> = Grid2D::from_shape_vec(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+        let items = grid.par_iter().collect::<Vec<_>>();
+        assert_eq!(items, grid.iter().collect::<Vec<_>>());
+
+        grid.transpose();
+        let items = grid.par_iter().collect::<Vec<_>>();
+        assert_eq!(items, grid.iter().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,633 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+use crate::{
+    prelude::{Connectivity, Coordinate, Grid2D, HashMap},
+    search::astar_bounded,
+};
+
+impl<T: Clone> Grid2D<T> {
+    /// Counts the simple paths (paths that do not visit a cell twice) from
+    /// `start` to `goal` that only move through cells for which `passable`
+    /// returns true.
+    ///
+    /// NOTE: This enumerates every path using depth-first search, so the
+    /// runtime is exponential in the size of the grid. It is only intended
+    /// for small inputs.
+    pub fn count_paths(
+        &self,
+        start: Coordinate,
+        goal: Coordinate,
+        passable: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> usize {
+        let mut count = 0;
+
+        self.simple_paths(start, goal, &passable, connectivity, &mut |_| count += 1);
+
+        count
+    }
+
+    /// Returns the length (in steps) of the longest simple path from `start`
+    /// to `goal`, or `None` if `goal` can't be reached.
+    ///
+    /// NOTE: Like `count_paths`, this is exponential and only intended for
+    /// small inputs.
+    pub fn longest_path_len(
+        &self,
+        start: Coordinate,
+        goal: Coordinate,
+        passable: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> Option<usize> {
+        let mut longest = None;
+
+        self.simple_paths(start, goal, &passable, connectivity, &mut |len| {
+            longest = longest.max(Some(len))
+        });
+
+        longest
+    }
+
+    /// Computes the minimum cost of reaching every cell from `start` using
+    /// Dijkstra's algorithm.
+    ///
+    /// `cost` is called with the values of the current cell and the neighbor
+    /// that is being moved into, and returns the cost of the move, or `None` if
+    /// the move is not possible. Cells that can't be reached are `None` in the
+    /// returned grid.
+    #[must_use]
+    pub fn cost_field(
+        &self,
+        start: Coordinate,
+        cost: impl Fn(&T, &T) -> Option<u64>,
+        connectivity: Connectivity,
+    ) -> Grid2D<Option<u64>> {
+        let mut field = Grid2D::new(self.width(), self.height(), None);
+
+        if !self.contains_coord(start) {
+            return field;
+        }
+
+        let mut queue = BinaryHeap::new();
+
+        field[start] = Some(0);
+        queue.push(Reverse((0, start)));
+
+        while let Some(Reverse((dist, cur))) = queue.pop() {
+            if field[cur].is_some_and(|best| best < dist) {
+                continue;
+            }
+
+            for (next, value) in self.neighbors_of(cur, connectivity) {
+                let Some(step) = cost(&self[cur], value) else {
+                    continue;
+                };
+
+                let next_dist = dist + step;
+
+                if field[next].is_none_or(|best| next_dist < best) {
+                    field[next] = Some(next_dist);
+                    queue.push(Reverse((next_dist, next)));
+                }
+            }
+        }
+
+        field
+    }
+
+    /// Computes the shortest distances (in steps) between every pair of the
+    /// given points, moving only through cells for which `passable` returns
+    /// true.
+    ///
+    /// The result is a matrix where `result[i][j]` is the distance from
+    /// `points[i]` to `points[j]`, or `u64::MAX` if `points[j]` can't be
+    /// reached. This collapses a maze into a complete graph over the points of
+    /// interest, e.g. as input for `held_karp`.
+    #[must_use]
+    pub fn poi_distances(
+        &self,
+        points: &[Coordinate],
+        passable: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> Vec<Vec<u64>> {
+        points
+            .iter()
+            .map(|&from| {
+                let field = self.cost_field(from, |_, to| passable(to).then_some(1), connectivity);
+
+                points
+                    .iter()
+                    .map(|&to| field.get(to).copied().flatten().unwrap_or(u64::MAX))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns a successor function for use with the search functions (e.g.
+    /// `astar_bounded`) that yields the passable neighbors of a coordinate,
+    /// each with a cost of 1.
+    pub fn as_graph<'a>(
+        &'a self,
+        passable: impl Fn(&T) -> bool + 'a,
+        connectivity: Connectivity,
+    ) -> impl Fn(&Coordinate) -> Vec<(Coordinate, u64)> + 'a {
+        self.as_weighted_graph(
+            move |_, to| if passable(to) { Some(1) } else { None },
+            connectivity,
+        )
+    }
+
+    /// Like [`Grid2D::as_graph`], but additionally allows moving from every
+    /// key of `portals` to the corresponding value at a cost of 1, if the
+    /// target is passable.
+    ///
+    /// Portals are one-way; insert both directions for a two-way portal.
+    pub fn as_graph_with_portals<'a>(
+        &'a self,
+        passable: impl Fn(&T) -> bool + 'a,
+        connectivity: Connectivity,
+        portals: &'a HashMap<Coordinate, Coordinate>,
+    ) -> impl Fn(&Coordinate) -> Vec<(Coordinate, u64)> + 'a {
+        move |coord| {
+            let mut successors = self
+                .neighbors_of(*coord, connectivity)
+                .filter(|(_, value)| passable(value))
+                .map(|(next, _)| (next, 1))
+                .collect::<Vec<_>>();
+
+            if let Some(&target) = portals.get(coord) {
+                if self.get(target).is_some_and(&passable) {
+                    successors.push((target, 1));
+                }
+            }
+
+            successors
+        }
+    }
+
+    /// Like [`Grid2D::as_graph`], but moving off one edge of the grid enters
+    /// it again from the opposite edge.
+    ///
+    /// The successors are always wrapped into the bounds of the grid, so every
+    /// cell has exactly one coordinate and a search over the returned graph
+    /// terminates.
+    pub fn as_graph_wrap<'a>(
+        &'a self,
+        passable: impl Fn(&T) -> bool + 'a,
+        connectivity: Connectivity,
+    ) -> impl Fn(&Coordinate) -> Vec<(Coordinate, u64)> + 'a {
+        move |&coord| {
+            connectivity
+                .directions()
+                .map(|dir| coord.add_wrapping(dir.into(), self.dims()))
+                .filter(|&next| passable(&self[next]))
+                .map(|next| (next, 1))
+                .collect()
+        }
+    }
+
+    /// Like [`Grid2D::as_graph`], but the cost of each move is determined by
+    /// `cost`, which is called with the values of the current cell and the
+    /// neighbor. Moves for which `cost` returns `None` are not possible.
+    pub fn as_weighted_graph<'a>(
+        &'a self,
+        cost: impl Fn(&T, &T) -> Option<u64> + 'a,
+        connectivity: Connectivity,
+    ) -> impl Fn(&Coordinate) -> Vec<(Coordinate, u64)> + 'a {
+        move |&coord| {
+            let Some(from) = self.get(coord) else {
+                return Vec::new();
+            };
+
+            self.neighbors_of(coord, connectivity)
+                .filter_map(|(next, to)| cost(from, to).map(|c| (next, c)))
+                .collect()
+        }
+    }
+
+    /// Finds the cheapest path from `start` to a cell for which `goal` returns
+    /// true, where each step can also update an auxiliary state (e.g. the set
+    /// of keys collected so far).
+    ///
+    /// The search runs over `(Coordinate, S)` pairs. `transition` is called with
+    /// the current cell, the current state, the neighbor being moved into and
+    /// its value, and returns the new state and the cost of the move, or `None`
+    /// if the move is not possible (e.g. a locked door).
+    ///
+    /// Returns the path, including the start, and its cost, or `None` if no goal
+    /// can be reached.
+    pub fn shortest_path_stateful<S: Hash + Eq + Clone>(
+        &self,
+        start: Coordinate,
+        initial_state: S,
+        goal: impl Fn(Coordinate, &S) -> bool,
+        transition: impl Fn(Coordinate, &S, Coordinate, &T) -> Option<(S, u64)>,
+        connectivity: Connectivity,
+    ) -> Option<(Vec<(Coordinate, S)>, u64)> {
+        if !self.contains_coord(start) {
+            return None;
+        }
+
+        let successors = |(coord, state): &(Coordinate, S)| {
+            self.neighbors_of(*coord, connectivity)
+                .filter_map(|(next, value)| {
+                    transition(*coord, state, next, value)
+                        .map(|(next_state, cost)| ((next, next_state), cost))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        astar_bounded(
+            &(start, initial_state),
+            successors,
+            |(coord, state)| goal(*coord, state),
+            |_| 0,
+            u64::MAX,
+        )
+    }
+
+    // Calls `on_path` with the length of every simple path from start to goal.
+    fn simple_paths(
+        &self,
+        start: Coordinate,
+        goal: Coordinate,
+        passable: &dyn Fn(&T) -> bool,
+        connectivity: Connectivity,
+        on_path: &mut dyn FnMut(usize),
+    ) {
+        let start_ok = self.get(start).is_some_and(passable);
+        let goal_ok = self.get(goal).is_some_and(passable);
+
+        if !start_ok || !goal_ok {
+            return;
+        }
+
+        let mut search = SimplePaths {
+            grid: self,
+            goal,
+            passable,
+            connectivity,
+            visited: Grid2D::new(self.width(), self.height(), false),
+            on_path,
+        };
+
+        search.visit(start, 0);
+    }
+}
+
+// State for the backtracking search behind `count_paths` and
+// `longest_path_len`.
+struct SimplePaths<'a, T: Clone> {
+    grid: &'a Grid2D<T>,
+    goal: Coordinate,
+    passable: &'a dyn Fn(&T) -> bool,
+    connectivity: Connectivity,
+    visited: Grid2D<bool>,
+    on_path: &'a mut dyn FnMut(usize),
+}
+
+impl<T: Clone> SimplePaths<'_, T> {
+    fn visit(&mut self, cur: Coordinate, depth: usize) {
+        if cur == self.goal {
+            (self.on_path)(depth);
+            return;
+        }
+
+        self.visited[cur] = true;
+
+        for dir in self.connectivity.directions() {
+            let next = cur + dir;
+
+            if self.visited.get(next) == Some(&false) && (self.passable)(&self.grid[next]) {
+                self.visit(next, depth + 1);
+            }
+        }
+
+        self.visited[cur] = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_count_paths() {
+        let grid: Grid2D<char> = Grid2D::new(3, 3, '.');
+
+        let paths = grid.count_paths(
+            Coordinate::new(0, 0),
+            Coordinate::new(2, 2),
+            |c| *c == '.',
+            Connectivity::VonNeumann,
+        );
+
+        assert_eq!(paths, 12);
+
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ...
+            .#.
+            ...
+        "});
+
+        let paths = grid.count_paths(
+            Coordinate::new(0, 0),
+            Coordinate::new(2, 2),
+            |c| *c == '.',
+            Connectivity::VonNeumann,
+        );
+
+        assert_eq!(paths, 2);
+    }
+
+    #[test]
+    fn test_count_paths_unreachable() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            .#.
+            #..
+            ...
+        "});
+
+        let paths = grid.count_paths(
+            Coordinate::new(0, 0),
+            Coordinate::new(2, 2),
+            |c| *c == '.',
+            Connectivity::VonNeumann,
+        );
+
+        assert_eq!(paths, 0);
+
+        let paths = grid.count_paths(
+            Coordinate::new(0, 0),
+            Coordinate::new(2, 2),
+            |c| *c == '.',
+            Connectivity::Moore,
+        );
+
+        assert!(paths > 0);
+    }
+
+    #[test]
+    fn test_longest_path_len() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ...
+            .#.
+            ...
+        "});
+
+        let longest = grid.longest_path_len(
+            Coordinate::new(0, 0),
+            Coordinate::new(2, 2),
+            |c| *c == '.',
+            Connectivity::VonNeumann,
+        );
+
+        assert_eq!(longest, Some(4));
+
+        let grid: Grid2D<char> = Grid2D::new(3, 3, '.');
+
+        let longest = grid.longest_path_len(
+            Coordinate::new(0, 0),
+            Coordinate::new(2, 2),
+            |c| *c == '.',
+            Connectivity::VonNeumann,
+        );
+
+        assert_eq!(longest, Some(8));
+
+        let longest = grid.longest_path_len(
+            Coordinate::new(0, 0),
+            Coordinate::new(5, 5),
+            |c| *c == '.',
+            Connectivity::VonNeumann,
+        );
+
+        assert_eq!(longest, None);
+    }
+
+    #[test]
+    fn test_cost_field() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            119
+            9#1
+            111
+        "});
+
+        let cost = |_: &char, to: &char| to.to_digit(10).map(u64::from);
+        let field = grid.cost_field(Coordinate::new(0, 0), cost, Connectivity::VonNeumann);
+
+        assert_eq!(field[Coordinate::new(0, 0)], Some(0));
+        assert_eq!(field[Coordinate::new(1, 0)], Some(1));
+        assert_eq!(field[Coordinate::new(2, 0)], Some(10));
+        assert_eq!(field[Coordinate::new(0, 1)], Some(9));
+        assert_eq!(field[Coordinate::new(1, 1)], None);
+        assert_eq!(field[Coordinate::new(0, 2)], Some(10));
+        assert_eq!(field[Coordinate::new(2, 2)], Some(12));
+        assert_eq!(field[Coordinate::new(2, 1)], Some(11));
+    }
+
+    #[test]
+    fn test_as_graph_with_astar() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ..#..
+            ..#..
+            .....
+        "});
+
+        let start = Coordinate::new(0, 0);
+        let goal = Coordinate::new(4, 0);
+
+        let (path, cost) = astar_bounded(
+            &start,
+            grid.as_graph(|c| *c != '#', Connectivity::VonNeumann),
+            |c| *c == goal,
+            |c| c.manhattan_distance(goal) as u64,
+            u64::MAX,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert!(path.iter().all(|&c| grid[c] != '#'));
+
+        let (_, cost) = astar_bounded(
+            &start,
+            grid.as_graph(|c| *c != '#', Connectivity::Moore),
+            |c| *c == goal,
+            |_| 0,
+            u64::MAX,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 4);
+    }
+
+    #[test]
+    fn test_as_weighted_graph() {
+        let grid: Grid2D<u8> = Grid2D::from_shape_vec(3, 2, vec![1, 9, 1, 1, 1, 1]);
+
+        let successors = grid.as_weighted_graph(
+            |_, to| if *to < 5 { Some(u64::from(*to)) } else { None },
+            Connectivity::VonNeumann,
+        );
+
+        assert_eq!(
+            successors(&Coordinate::new(0, 0)),
+            vec![(Coordinate::new(0, 1), 1)]
+        );
+        assert!(successors(&Coordinate::new(7, 7)).is_empty());
+
+        let (_, cost) = astar_bounded(
+            &Coordinate::new(0, 0),
+            successors,
+            |c| *c == Coordinate::new(2, 0),
+            |_| 0,
+            u64::MAX,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 4);
+    }
+
+    #[test]
+    fn test_as_graph_with_portals() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ..#..
+            ..#..
+            ..#..
+        "});
+
+        let start = Coordinate::new(0, 0);
+        let goal = Coordinate::new(4, 2);
+        let passable = |c: &char| *c != '#';
+
+        let without = grid.as_graph(passable, Connectivity::VonNeumann);
+        assert!(astar_bounded(&start, without, |c| *c == goal, |_| 0, u64::MAX).is_none());
+
+        let mut portals = HashMap::default();
+        portals.insert(Coordinate::new(1, 0), Coordinate::new(3, 2));
+
+        let with = grid.as_graph_with_portals(passable, Connectivity::VonNeumann, &portals);
+        let (path, cost) = astar_bounded(&start, with, |c| *c == goal, |_| 0, u64::MAX).unwrap();
+
+        assert_eq!(cost, 3);
+        assert_eq!(
+            path,
+            vec![start, Coordinate::new(1, 0), Coordinate::new(3, 2), goal]
+        );
+    }
+
+    #[test]
+    fn test_shortest_path_stateful() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            #########
+            #b.A.@.a#
+            #########
+        "});
+
+        let start = Coordinate::new(5, 1);
+
+        // The state is a bitmask of the keys collected so far
+        let transition = |_, keys: &u32, _, value: &char| match *value {
+            '#' => None,
+            'A'..='Z' if keys & (1 << (*value as u8 - b'A')) == 0 => None,
+            'a'..='z' => Some((keys | (1 << (*value as u8 - b'a')), 1)),
+            _ => Some((*keys, 1)),
+        };
+
+        let (path, cost) = grid
+            .shortest_path_stateful(
+                start,
+                0u32,
+                |_, keys| *keys == 0b11,
+                transition,
+                Connectivity::VonNeumann,
+            )
+            .unwrap();
+
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path[2], (Coordinate::new(7, 1), 0b01));
+        assert_eq!(path[8], (Coordinate::new(1, 1), 0b11));
+
+        // Without the key for door A, key b can't be reached
+        let result = grid.shortest_path_stateful(
+            start,
+            0u32,
+            |_, keys| *keys & 0b10 != 0,
+            |from, keys, to, value| {
+                if *value == 'a' {
+                    None
+                } else {
+                    transition(from, keys, to, value)
+                }
+            },
+            Connectivity::VonNeumann,
+        );
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_as_graph_wrap() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            .#...
+            .#...
+            .#...
+        "});
+
+        let start = Coordinate::new(0, 0);
+        let goal = Coordinate::new(4, 2);
+
+        let graph = grid.as_graph_wrap(|c| *c != '#', Connectivity::VonNeumann);
+
+        let mut successors = graph(&start);
+        successors.sort();
+        assert_eq!(
+            successors,
+            vec![
+                (Coordinate::new(4, 0), 1),
+                (Coordinate::new(0, 1), 1),
+                (Coordinate::new(0, 2), 1),
+            ]
+        );
+
+        let (path, cost) = astar_bounded(&start, graph, |c| *c == goal, |_| 0, u64::MAX).unwrap();
+
+        assert_eq!(cost, 2);
+        assert_eq!(path.len(), 3);
+        assert!(path.iter().all(|&c| grid.contains_coord(c)));
+    }
+
+    #[test]
+    fn test_poi_distances() {
+        // AoC 2016, day 24 example
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ###########
+            #0.1.....2#
+            #.#######.#
+            #4.......3#
+            ###########
+        "});
+
+        let points = ('0'..='4')
+            .map(|c| grid.iter().find(|(_, &v)| v == c).unwrap().0)
+            .collect::<Vec<_>>();
+
+        let distances = grid.poi_distances(&points, |c| *c != '#', Connectivity::VonNeumann);
+
+        assert_eq!(distances[0], vec![0, 2, 8, 10, 2]);
+        assert_eq!(distances[4][1], 4);
+        assert!((0..5).all(|i| (0..5).all(|j| distances[i][j] == distances[j][i])));
+
+        let walled = grid.poi_distances(
+            &[Coordinate::new(1, 1), Coordinate::new(0, 0)],
+            |c| *c != '#',
+            Connectivity::VonNeumann,
+        );
+
+        assert_eq!(walled, vec![vec![0, u64::MAX], vec![u64::MAX, 0]]);
+    }
+}
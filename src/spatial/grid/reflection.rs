@@ -0,0 +1,80 @@
+use ndarray::{ArrayView1, Axis};
+
+use crate::prelude::Grid2D;
+
+impl<T: Clone + PartialEq> Grid2D<T> {
+    /// Finds a horizontal line of reflection, i.e. a row index `r` such that
+    /// the rows above `r` mirror the rows from `r` onwards (as far as both
+    /// sides extend).
+    ///
+    /// Exactly `smudges` cells must differ between the two sides, so passing
+    /// 0 finds a perfect reflection. Returns the first matching `r`, or `None`
+    /// if there is none.
+    #[must_use]
+    pub fn horizontal_reflection(&self, smudges: usize) -> Option<usize> {
+        find_reflection(&self.data.axis_iter(Axis(0)).collect::<Vec<_>>(), smudges)
+    }
+
+    /// Finds a vertical line of reflection, i.e. a column index `c` such that
+    /// the columns left of `c` mirror the columns from `c` onwards.
+    ///
+    /// See `horizontal_reflection` for the meaning of `smudges`.
+    #[must_use]
+    pub fn vertical_reflection(&self, smudges: usize) -> Option<usize> {
+        find_reflection(&self.data.axis_iter(Axis(1)).collect::<Vec<_>>(), smudges)
+    }
+}
+
+fn find_reflection<T: PartialEq>(lines: &[ArrayView1<T>], smudges: usize) -> Option<usize> {
+    (1..lines.len()).find(|&split| {
+        let mismatches: usize = lines[..split]
+            .iter()
+            .rev()
+            .zip(lines[split..].iter())
+            .map(|(a, b)| a.iter().zip(b.iter()).filter(|(x, y)| x != y).count())
+            .sum();
+
+        mismatches == smudges
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_vertical_reflection() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            #.##..##.
+            ..#.##.#.
+            ##......#
+            ##......#
+            ..#.##.#.
+            ..##..##.
+            #.#.##.#.
+        "});
+
+        assert_eq!(grid.vertical_reflection(0), Some(5));
+        assert_eq!(grid.horizontal_reflection(0), None);
+        assert_eq!(grid.horizontal_reflection(1), Some(3));
+    }
+
+    #[test]
+    fn test_horizontal_reflection() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            #...##..#
+            #....#..#
+            ..##..###
+            #####.##.
+            #####.##.
+            ..##..###
+            #....#..#
+        "});
+
+        assert_eq!(grid.horizontal_reflection(0), Some(4));
+        assert_eq!(grid.vertical_reflection(0), None);
+        assert_eq!(grid.horizontal_reflection(1), Some(1));
+    }
+}
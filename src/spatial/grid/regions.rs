@@ -0,0 +1,484 @@
+use crate::{
+    prelude::{Connectivity, Coordinate, Grid2D, HashSet, VisitedGrid},
+    spatial::region_corners,
+};
+
+impl<T: Clone + PartialEq> Grid2D<T> {
+    /// Replaces the connected region of equal cells around `start` with
+    /// `new_value`, like the paint bucket tool of an image editor.
+    ///
+    /// Returns the number of cells that were changed. If `start` is outside of
+    /// the grid or already contains `new_value`, nothing happens and 0 is
+    /// returned.
+    pub fn fill_region(
+        &mut self,
+        start: Coordinate,
+        new_value: T,
+        connectivity: Connectivity,
+    ) -> usize {
+        let Some(old_value) = self.get(start).cloned() else {
+            return 0;
+        };
+
+        if old_value == new_value {
+            return 0;
+        }
+
+        let mut stack = vec![start];
+        let mut changed = 1;
+
+        self.set(start, new_value.clone());
+
+        while let Some(cur) = stack.pop() {
+            for dir in connectivity.directions() {
+                let next = cur + dir;
+
+                if self.get(next) == Some(&old_value) {
+                    self.set(next, new_value.clone());
+                    stack.push(next);
+                    changed += 1;
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// Returns every maximal connected region of equal cells as a list of
+    /// coordinates.
+    ///
+    /// Each cell of the grid appears in exactly one region. The regions are
+    /// ordered by their first cell in row-major order.
+    #[must_use]
+    pub fn regions(&self, connectivity: Connectivity) -> Vec<Vec<Coordinate>> {
+        let mut visited = VisitedGrid::for_grid(self);
+        let mut regions = Vec::new();
+
+        for (coord, value) in self.iter() {
+            if !visited.visit(coord) {
+                continue;
+            }
+
+            let mut region = vec![coord];
+            let mut stack = vec![coord];
+
+            while let Some(cur) = stack.pop() {
+                for dir in connectivity.directions() {
+                    let next = cur + dir;
+
+                    if self.get(next) == Some(value) && visited.visit(next) {
+                        region.push(next);
+                        stack.push(next);
+                    }
+                }
+            }
+
+            regions.push(region);
+        }
+
+        regions
+    }
+}
+
+impl<T: Clone> Grid2D<T> {
+    /// Returns the number of cells in the connected region around `start` whose
+    /// values satisfy `predicate`.
+    ///
+    /// Returns 0 if `start` is outside of the grid or does not satisfy the
+    /// predicate itself.
+    #[must_use]
+    pub fn region_size(
+        &self,
+        start: Coordinate,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> usize {
+        self.region_size_with(start, predicate, connectivity, &mut HashSet::default())
+    }
+
+    /// Like [`Grid2D::region_size`], but uses the caller-provided `visited`
+    /// set, so that it can be reused across many calls.
+    ///
+    /// Cells that are already in `visited` are treated as not being part of the
+    /// region, and every cell of the region is added to `visited`. Calling this
+    /// for every cell of the grid with the same set thus counts every region
+    /// exactly once.
+    pub fn region_size_with(
+        &self,
+        start: Coordinate,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+        visited: &mut HashSet<Coordinate>,
+    ) -> usize {
+        if !self.get(start).is_some_and(&predicate) || !visited.insert(start) {
+            return 0;
+        }
+
+        let mut stack = vec![start];
+        let mut size = 1;
+
+        while let Some(cur) = stack.pop() {
+            for (next, value) in self.neighbors_of(cur, connectivity) {
+                if predicate(value) && visited.insert(next) {
+                    stack.push(next);
+                    size += 1;
+                }
+            }
+        }
+
+        size
+    }
+
+    /// Returns every cell that can be reached from `start` using the given move
+    /// set, only entering cells for which `passable` returns true.
+    ///
+    /// `moves` returns the candidate targets of a move from a coordinate, which
+    /// allows for non-standard adjacency such as knight moves or jumps. Targets
+    /// outside of the grid are ignored. The result includes `start` itself, or
+    /// is empty if `start` is outside of the grid or not passable.
+    #[must_use]
+    pub fn reachable(
+        &self,
+        start: Coordinate,
+        moves: impl Fn(Coordinate) -> Vec<Coordinate>,
+        passable: impl Fn(Coordinate, &T) -> bool,
+    ) -> HashSet<Coordinate> {
+        let mut reached = HashSet::default();
+
+        if !self.get(start).is_some_and(|value| passable(start, value)) {
+            return reached;
+        }
+
+        let mut visited = VisitedGrid::for_grid(self);
+        let mut stack = vec![start];
+
+        visited.visit(start);
+
+        while let Some(cur) = stack.pop() {
+            reached.insert(cur);
+
+            for next in moves(cur) {
+                if self.contains_coord(next) && passable(next, &self[next]) && visited.visit(next) {
+                    stack.push(next);
+                }
+            }
+        }
+
+        reached
+    }
+
+    /// Counts the corners of a region of this grid, which is the same as the
+    /// number of straight sides of its outline. See
+    /// [`spatial::region_corners`](crate::spatial::region_corners).
+    ///
+    /// The region is usually one of the vectors returned by `regions()`. In
+    /// debug builds, this panics if a coordinate lies outside of the grid.
+    #[must_use]
+    pub fn region_corners(&self, region: &[Coordinate]) -> usize {
+        debug_assert!(
+            region.iter().all(|&coord| self.contains_coord(coord)),
+            "The region must lie inside the grid"
+        );
+
+        region_corners(region)
+    }
+
+    /// Returns the cells that satisfy `predicate` but have at least one
+    /// neighbor that doesn't, i.e. the edge of the regions described by the
+    /// predicate, in row-major order.
+    ///
+    /// The area outside of the grid does not satisfy the predicate, so cells
+    /// on the border of the grid are boundary cells if they satisfy it.
+    #[must_use]
+    pub fn boundary_cells(
+        &self,
+        predicate: impl Fn(&T) -> bool,
+        connectivity: Connectivity,
+    ) -> Vec<Coordinate> {
+        let neighborhood_size = connectivity.directions().count() as u8;
+        let counts = self.neighbor_count_grid(&predicate, connectivity);
+
+        self.iter()
+            .filter(|&(coord, value)| predicate(value) && counts[coord] < neighborhood_size)
+            .map(|(coord, _)| coord)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_fill_region() {
+        let mut grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ..#.
+            .#..
+            #...
+        "});
+
+        let changed = grid.fill_region(Coordinate::new(0, 0), 'o', Connectivity::VonNeumann);
+
+        assert_eq!(changed, 3);
+        assert_eq!(
+            grid,
+            Grid2D::parse(indoc! {"
+                oo#.
+                o#..
+                #...
+            "})
+        );
+    }
+
+    #[test]
+    fn test_fill_region_moore() {
+        let mut grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ..#.
+            .#..
+            #...
+        "});
+
+        let changed = grid.fill_region(Coordinate::new(1, 1), 'o', Connectivity::Moore);
+
+        assert_eq!(changed, 3);
+        assert_eq!(
+            grid,
+            Grid2D::parse(indoc! {"
+                ..o.
+                .o..
+                o...
+            "})
+        );
+    }
+
+    #[test]
+    fn test_fill_region_noop() {
+        let mut grid: Grid2D<char> = Grid2D::new(3, 3, '.');
+
+        assert_eq!(
+            grid.fill_region(Coordinate::new(1, 1), '.', Connectivity::VonNeumann),
+            0
+        );
+
+        assert_eq!(
+            grid.fill_region(Coordinate::new(5, 5), '#', Connectivity::VonNeumann),
+            0
+        );
+
+        assert_eq!(grid, Grid2D::new(3, 3, '.'));
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            AAAA
+            BBCD
+            BBCC
+            EEEC
+        "});
+
+        let regions = grid.regions(Connectivity::VonNeumann);
+
+        let mut sizes = regions
+            .iter()
+            .map(|region| (grid[region[0]], region.len()))
+            .collect::<Vec<_>>();
+
+        sizes.sort();
+
+        assert_eq!(
+            sizes,
+            vec![('A', 4), ('B', 4), ('C', 4), ('D', 1), ('E', 3)]
+        );
+
+        let mut cells = regions.concat();
+        cells.sort();
+        cells.dedup();
+
+        assert_eq!(cells.len(), grid.area());
+    }
+
+    #[test]
+    fn test_regions_moore() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            #.#
+            .#.
+            #.#
+        "});
+
+        assert_eq!(grid.regions(Connectivity::VonNeumann).len(), 9);
+        assert_eq!(grid.regions(Connectivity::Moore).len(), 2);
+    }
+
+    #[test]
+    fn test_region_size() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ..#.
+            .#..
+            #...
+        "});
+
+        let open = |c: &char| *c == '.';
+
+        assert_eq!(
+            grid.region_size(Coordinate::new(0, 0), open, Connectivity::VonNeumann),
+            3
+        );
+        assert_eq!(
+            grid.region_size(Coordinate::new(3, 2), open, Connectivity::VonNeumann),
+            6
+        );
+        assert_eq!(
+            grid.region_size(Coordinate::new(0, 0), open, Connectivity::Moore),
+            9
+        );
+        assert_eq!(
+            grid.region_size(Coordinate::new(2, 0), open, Connectivity::VonNeumann),
+            0
+        );
+        assert_eq!(
+            grid.region_size(Coordinate::new(9, 9), open, Connectivity::VonNeumann),
+            0
+        );
+    }
+
+    #[test]
+    fn test_region_size_with() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ..#.
+            .#..
+            #...
+        "});
+
+        let mut visited = HashSet::default();
+
+        let sizes = grid
+            .iter()
+            .map(|(coord, _)| {
+                grid.region_size_with(coord, |c| *c == '.', Connectivity::VonNeumann, &mut visited)
+            })
+            .filter(|&size| size > 0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(sizes, vec![3, 6]);
+        assert_eq!(visited.len(), 9);
+    }
+
+    #[test]
+    fn test_region_corners() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            AAAA
+            BBCD
+            BBCC
+            EEEC
+        "});
+
+        let mut sides = grid
+            .regions(Connectivity::VonNeumann)
+            .iter()
+            .map(|region| (grid[region[0]], grid.region_corners(region)))
+            .collect::<Vec<_>>();
+
+        sides.sort();
+
+        assert_eq!(
+            sides,
+            vec![('A', 4), ('B', 4), ('C', 8), ('D', 4), ('E', 4)]
+        );
+    }
+
+    #[test]
+    fn test_region_corners_holes_and_diagonals() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            AAAAAA
+            AAABBA
+            AAABBA
+            ABBAAA
+            ABBAAA
+            AAAAAA
+        "});
+
+        let regions = grid.regions(Connectivity::VonNeumann);
+        let a = regions.iter().find(|r| grid[r[0]] == 'A').unwrap();
+
+        assert_eq!(grid.region_corners(a), 12);
+
+        // Two cells touching diagonally form a single Moore region
+        let diagonal = [Coordinate::new(0, 0), Coordinate::new(1, 1)];
+        assert_eq!(grid.region_corners(&diagonal), 8);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "The region must lie inside the grid")]
+    fn test_region_corners_outside_of_grid() {
+        let grid = Grid2D::new(2, 2, '.');
+        let _ = grid.region_corners(&[Coordinate::new(2, 0)]);
+    }
+
+    #[test]
+    fn test_boundary_cells() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            .....
+            .###.
+            .###.
+            .###.
+            ....#
+        "});
+
+        let is_wall = |c: &char| *c == '#';
+
+        let boundary = grid.boundary_cells(is_wall, Connectivity::VonNeumann);
+        assert_eq!(boundary.len(), 9);
+        assert!(!boundary.contains(&Coordinate::new(2, 2)));
+        assert_eq!(boundary[0], Coordinate::new(1, 1));
+        assert_eq!(boundary[8], Coordinate::new(4, 4));
+
+        let boundary = grid.boundary_cells(|c| *c == '.', Connectivity::Moore);
+        assert_eq!(boundary.len(), 15);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            ....
+            .#..
+            ....
+            ...#
+        "});
+
+        let open = |_, c: &char| *c == '.';
+
+        let knight = grid.reachable(
+            Coordinate::new(0, 0),
+            |c| c.knight_move_neighbors().collect(),
+            open,
+        );
+
+        assert_eq!(knight.len(), 14);
+        assert!(!knight.contains(&Coordinate::new(1, 1)));
+
+        // Jumping two cells to the right only
+        let jumps = grid.reachable(
+            Coordinate::new(0, 0),
+            |c| vec![c + Coordinate::new(2, 0)],
+            open,
+        );
+
+        assert_eq!(
+            jumps,
+            [Coordinate::new(0, 0), Coordinate::new(2, 0)]
+                .into_iter()
+                .collect()
+        );
+
+        assert!(grid
+            .reachable(Coordinate::new(1, 1), |c| c.neighbors().collect(), open)
+            .is_empty());
+        assert!(grid
+            .reachable(Coordinate::new(9, 9), |c| c.neighbors().collect(), open)
+            .is_empty());
+    }
+}
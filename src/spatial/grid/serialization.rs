@@ -0,0 +1,153 @@
+//! Serde support for `Grid2D` (requires the `serde` feature).
+//!
+//! A grid is serialized as a struct with its `width`, `height` and `data`,
+//! where `data` holds the cells in row-major order. Deserialization checks that
+//! the number of cells matches the dimensions.
+
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::prelude::Grid2D;
+
+impl<T: Clone + Serialize> Serialize for Grid2D<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Grid2D", 3)?;
+
+        state.serialize_field("width", &self.width())?;
+        state.serialize_field("height", &self.height())?;
+        state.serialize_field("data", &RowMajor(self))?;
+
+        state.end()
+    }
+}
+
+// Serializes the cells of a grid in row-major order, without copying them
+// even if the backing array is in a different memory order.
+struct RowMajor<'a, T: Clone>(&'a Grid2D<T>);
+
+impl<T: Clone + Serialize> Serialize for RowMajor<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|(_, value)| value))
+    }
+}
+
+// The serialized form of a grid
+#[derive(Deserialize)]
+#[serde(rename = "Grid2D")]
+struct RawGrid<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<'de, T: Clone + Deserialize<'de>> Deserialize<'de> for Grid2D<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawGrid::<T>::deserialize(deserializer)?;
+
+        if raw.width == 0 || raw.height == 0 {
+            return Err(D::Error::custom("grid dimensions must be greater than 0"));
+        }
+
+        if raw.width > i32::MAX as usize || raw.height > i32::MAX as usize {
+            return Err(D::Error::custom(format!(
+                "grid dimensions must not exceed {}",
+                i32::MAX
+            )));
+        }
+
+        let cells = raw
+            .width
+            .checked_mul(raw.height)
+            .ok_or_else(|| D::Error::custom("grid dimensions are too large"))?;
+
+        if raw.data.len() != cells {
+            return Err(D::Error::custom(format!(
+                "expected {} cells for a {}x{} grid, found {}",
+                cells,
+                raw.width,
+                raw.height,
+                raw.data.len()
+            )));
+        }
+
+        Ok(Grid2D::from_shape_vec(raw.width, raw.height, raw.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Coordinate, Direction};
+
+    use super::*;
+
+    #[test]
+    fn test_grid_roundtrip() {
+        let grid = Grid2D::from_shape_vec(3, 2, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(
+            json,
+            r#"{"width":3,"height":2,"data":["a","b","c","d","e","f"]}"#
+        );
+
+        let parsed: Grid2D<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, grid);
+
+        // The cells are written in row-major order even after a transpose
+        let mut transposed = grid;
+        transposed.transpose();
+
+        let json = serde_json::to_string(&transposed).unwrap();
+        assert_eq!(
+            json,
+            r#"{"width":2,"height":3,"data":["a","d","b","e","c","f"]}"#
+        );
+
+        let parsed: Grid2D<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, transposed);
+    }
+
+    #[test]
+    fn test_grid_dimension_mismatch() {
+        let result = serde_json::from_str::<Grid2D<u8>>(r#"{"width":2,"height":2,"data":[1,2,3]}"#);
+        assert!(result.is_err());
+
+        let result = serde_json::from_str::<Grid2D<u8>>(r#"{"width":0,"height":0,"data":[]}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_grid_dimensions_too_large() {
+        let result =
+            serde_json::from_str::<Grid2D<u8>>(r#"{"width":2147483648,"height":1,"data":[]}"#);
+        assert!(result.is_err());
+
+        let result =
+            serde_json::from_str::<Grid2D<u8>>(r#"{"width":1,"height":2147483648,"data":[]}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_grid_cell_count_overflow() {
+        // Both dimensions fit into an i32, but on 32-bit targets their product
+        // overflows a usize; on 64-bit targets it just doesn't match the data.
+        let result = serde_json::from_str::<Grid2D<u8>>(
+            r#"{"width":2147483647,"height":2147483647,"data":[1]}"#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_coordinate_and_direction() {
+        let coord = Coordinate::new(-3, 7);
+        let json = serde_json::to_string(&coord).unwrap();
+
+        assert_eq!(json, r#"{"x":-3,"y":7}"#);
+        assert_eq!(serde_json::from_str::<Coordinate>(&json).unwrap(), coord);
+
+        let json = serde_json::to_string(&Direction::UpLeft).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Direction>(&json).unwrap(),
+            Direction::UpLeft
+        );
+    }
+}
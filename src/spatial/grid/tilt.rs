@@ -0,0 +1,155 @@
+use crate::prelude::{Coordinate, Direction, Grid2D};
+
+impl<T: Clone + PartialEq> Grid2D<T> {
+    /// Tilts the grid in the given direction, sliding every `movable` cell as
+    /// far as possible through `empty` cells, until it hits another movable
+    /// cell, the edge of the grid or any other cell, which acts as a blocker.
+    /// The cells the movable cells leave behind become `empty`.
+    ///
+    /// Panics if `dir` is not one of the four cardinal directions.
+    pub fn tilt(&mut self, dir: Direction, movable: &T, empty: &T) {
+        let (w, h) = (self.width, self.height);
+
+        // Each line is walked starting at the edge the cells slide towards.
+        let (lines, len) = match dir {
+            Direction::Up | Direction::Down => (w, h),
+            Direction::Left | Direction::Right => (h, w),
+            _ => panic!("Can only tilt in a cardinal direction"),
+        };
+
+        let cell = |line: i32, i: i32| match dir {
+            Direction::Up => Coordinate::new(line, i),
+            Direction::Down => Coordinate::new(line, h - 1 - i),
+            Direction::Left => Coordinate::new(i, line),
+            _ => Coordinate::new(w - 1 - i, line),
+        };
+
+        for line in 0..lines {
+            // The position the next movable cell would slide to
+            let mut free = 0;
+
+            for i in 0..len {
+                let coord = cell(line, i);
+                let value = &self[coord];
+
+                if value == movable {
+                    if free != i {
+                        self[cell(line, free)] = movable.clone();
+                        self[coord] = empty.clone();
+                    }
+
+                    free += 1;
+                } else if value != empty {
+                    free = i + 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn tilted(dir: Direction) -> Grid2D<char> {
+        let mut grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            O.#.
+            .O.O
+            O..#
+            ..O.
+        "});
+
+        grid.tilt(dir, &'O', &'.');
+        grid
+    }
+
+    #[test]
+    fn test_tilt_up() {
+        assert_eq!(
+            tilted(Direction::Up),
+            Grid2D::parse(indoc! {"
+                OO#O
+                O.O.
+                ...#
+                ....
+            "})
+        );
+    }
+
+    #[test]
+    fn test_tilt_down() {
+        assert_eq!(
+            tilted(Direction::Down),
+            Grid2D::parse(indoc! {"
+                ..#.
+                ...O
+                O..#
+                OOO.
+            "})
+        );
+    }
+
+    #[test]
+    fn test_tilt_left() {
+        assert_eq!(
+            tilted(Direction::Left),
+            Grid2D::parse(indoc! {"
+                O.#.
+                OO..
+                O..#
+                O...
+            "})
+        );
+    }
+
+    #[test]
+    fn test_tilt_right() {
+        assert_eq!(
+            tilted(Direction::Right),
+            Grid2D::parse(indoc! {"
+                .O#.
+                ..OO
+                ..O#
+                ...O
+            "})
+        );
+    }
+
+    #[test]
+    fn test_tilt_stacks() {
+        let mut grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            .O.O.#O.O
+            OOO......
+        "});
+
+        grid.tilt(Direction::Right, &'O', &'.');
+
+        assert_eq!(
+            grid,
+            Grid2D::parse(indoc! {"
+                ...OO#.OO
+                ......OOO
+            "})
+        );
+    }
+
+    #[test]
+    fn test_tilt_other_cells_block() {
+        let mut grid: Grid2D<char> = Grid2D::parse(indoc! {"
+            .O.xO.#O
+            O..x...O
+        "});
+
+        grid.tilt(Direction::Left, &'O', &'.');
+
+        assert_eq!(
+            grid,
+            Grid2D::parse(indoc! {"
+                O..xO.#O
+                O..xO...
+            "})
+        );
+    }
+}
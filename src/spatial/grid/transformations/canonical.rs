@@ -0,0 +1,104 @@
+use std::cmp::Ordering;
+
+use crate::prelude::Grid2D;
+
+impl<T: Clone + Ord> Grid2D<T> {
+    /// Returns the canonical form of the grid under rotation and reflection.
+    ///
+    /// This is the smallest of the eight rotated and/or flipped versions of the
+    /// grid, compared by dimensions first and then by their cells in row-major
+    /// order. Two grids that are equal up to symmetry have the same canonical
+    /// form.
+    #[must_use]
+    pub fn canonical(&self) -> Self {
+        let mut best = self.clone();
+        let mut cur = self.clone();
+
+        for i in 0..8 {
+            if i == 4 {
+                cur.flip_x();
+            }
+
+            if cmp_row_major(&cur, &best) == Ordering::Less {
+                best = cur.clone();
+            }
+
+            cur.rotate_right();
+        }
+
+        best
+    }
+}
+
+fn cmp_row_major<T: Clone + Ord>(a: &Grid2D<T>, b: &Grid2D<T>) -> Ordering {
+    (a.height, a.width)
+        .cmp(&(b.height, b.width))
+        .then_with(|| a.data.iter().cmp(b.data.iter()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical() {
+        let grid = Grid2D::from_shape_vec(
+            3,
+            2,
+            vec![
+                1, 2, 3, //
+                4, 5, 6, //
+            ],
+        );
+
+        let canonical = grid.canonical();
+
+        let mut other = grid.clone();
+        other.rotate_right();
+        assert_eq!(other.canonical(), canonical);
+
+        other.flip_y();
+        assert_eq!(other.canonical(), canonical);
+
+        other.transpose();
+        assert_eq!(other.canonical(), canonical);
+
+        assert_eq!(canonical.canonical(), canonical);
+    }
+
+    #[test]
+    fn test_canonical_is_smallest() {
+        let grid = Grid2D::from_shape_vec(
+            2,
+            2,
+            vec![
+                4, 3, //
+                1, 2, //
+            ],
+        );
+
+        assert_eq!(
+            grid.canonical(),
+            Grid2D::from_shape_vec(
+                2,
+                2,
+                vec![
+                    1, 2, //
+                    4, 3, //
+                ],
+            )
+        );
+    }
+
+    #[test]
+    fn test_canonical_distinguishes_shapes() {
+        // An L-tromino and a straight tromino
+        let l = Grid2D::from_shape_vec(2, 2, vec![1, 0, 1, 1]);
+        let i = Grid2D::from_shape_vec(3, 1, vec![1, 1, 1]);
+
+        assert_ne!(l.canonical(), i.canonical());
+
+        let l2 = Grid2D::from_shape_vec(2, 2, vec![1, 1, 0, 1]);
+        assert_eq!(l.canonical(), l2.canonical());
+    }
+}
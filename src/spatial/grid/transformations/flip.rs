@@ -12,6 +12,12 @@ impl<T: Clone> Grid2D<T> {
     pub fn flip_y(&mut self) {
         self.data.invert_axis(Axis(0));
     }
+
+    /// Flips the grid along the anti-diagonal (top-right to bottom-left)
+    pub fn anti_transpose(&mut self) {
+        self.rotate_180();
+        self.transpose();
+    }
 }
 
 #[cfg(test)]
@@ -70,4 +76,34 @@ mod tests {
         grid.flip_y();
         assert_eq!(grid, expected);
     }
+
+    #[test]
+    fn test_anti_transpose() {
+        let mut grid = Grid2D::from_shape_vec(
+            3,
+            2,
+            vec![
+                1, 2, 3, //
+                4, 5, 6, //
+            ],
+        );
+
+        let expected = Grid2D::from_shape_vec(
+            2,
+            3,
+            vec![
+                6, 3, //
+                5, 2, //
+                4, 1, //
+            ],
+        );
+
+        let original = grid.clone();
+
+        grid.anti_transpose();
+        assert_eq!(grid, expected);
+
+        grid.anti_transpose();
+        assert_eq!(grid, original);
+    }
 }
@@ -1,3 +1,4 @@
+pub mod canonical;
 pub mod flip;
 pub mod replicate;
 pub mod rotate;
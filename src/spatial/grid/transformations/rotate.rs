@@ -18,6 +18,23 @@ impl<T: Clone> Grid2D<T> {
         self.flip_x();
         self.flip_y();
     }
+
+    /// Returns a copy of the grid rotated by `quarter_turns` times 90 degrees
+    /// clockwise. Negative values rotate counter-clockwise, and any multiple of
+    /// four leaves the grid unchanged.
+    #[must_use]
+    pub fn rotate(&self, quarter_turns: i32) -> Self {
+        let mut rotated = self.clone();
+
+        match quarter_turns.rem_euclid(4) {
+            1 => rotated.rotate_right(),
+            2 => rotated.rotate_180(),
+            3 => rotated.rotate_left(),
+            _ => {}
+        }
+
+        rotated
+    }
 }
 
 #[cfg(test)]
@@ -104,4 +121,38 @@ mod tests {
 
         assert_eq!(grid, expected);
     }
+
+    #[test]
+    fn test_rotate() {
+        let grid = Grid2D::from_shape_vec(
+            3,
+            2,
+            vec![
+                1, 2, 3, //
+                4, 5, 6, //
+            ],
+        );
+
+        let mut expected = grid.clone();
+
+        for quarter_turns in 0..=8 {
+            assert_eq!(grid.rotate(quarter_turns), expected);
+            assert_eq!(grid.rotate(quarter_turns - 8), expected);
+            expected.rotate_right();
+        }
+
+        assert_eq!(grid.rotate(-1).width(), 2);
+        assert_eq!(
+            grid.rotate(-1),
+            Grid2D::from_shape_vec(
+                2,
+                3,
+                vec![
+                    3, 6, //
+                    2, 5, //
+                    1, 4, //
+                ],
+            )
+        );
+    }
 }
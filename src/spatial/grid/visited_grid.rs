@@ -0,0 +1,111 @@
+use crate::prelude::{Coordinate, Grid2D};
+
+/// A compact set of visited cells for grid searches.
+///
+/// This stores one bit per cell (indexed by `y * width + x`), which is much
+/// smaller and faster than a `HashSet<Coordinate>`. It can be reused across
+/// searches by calling [`VisitedGrid::clear`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VisitedGrid {
+    width: i32,
+    height: i32,
+    bits: Vec<u64>,
+}
+
+impl VisitedGrid {
+    /// Creates an empty visited set for a grid of the given size.
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width: width as i32,
+            height: height as i32,
+            bits: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    /// Creates an empty visited set with the same dimensions as `grid`.
+    #[must_use]
+    pub fn for_grid<T: Clone>(grid: &Grid2D<T>) -> Self {
+        Self::new(grid.width(), grid.height())
+    }
+
+    /// Marks the coordinate as visited and returns whether it was newly
+    /// visited, similar to `HashSet::insert`.
+    ///
+    /// Panics if the coordinate is outside of the grid.
+    pub fn visit(&mut self, coord: Coordinate) -> bool {
+        let (word, mask) = self
+            .position(coord)
+            .unwrap_or_else(|| panic!("Coordinate {coord} is outside of the grid"));
+
+        let newly_visited = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+
+        newly_visited
+    }
+
+    /// Returns whether the coordinate has been visited. Coordinates outside of
+    /// the grid are never visited.
+    #[must_use]
+    pub fn is_visited(&self, coord: Coordinate) -> bool {
+        self.position(coord)
+            .is_some_and(|(word, mask)| self.bits[word] & mask != 0)
+    }
+
+    /// Returns the number of visited cells.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Marks every cell as unvisited.
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+
+    // Returns the index of the word and the bit mask for the coordinate.
+    fn position(&self, coord: Coordinate) -> Option<(usize, u64)> {
+        if coord.x < 0 || coord.y < 0 || coord.x >= self.width || coord.y >= self.height {
+            return None;
+        }
+
+        let index = (coord.y * self.width + coord.x) as usize;
+
+        Some((index / 64, 1 << (index % 64)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visit() {
+        let mut visited = VisitedGrid::new(10, 10);
+
+        assert!(!visited.is_visited(Coordinate::new(3, 7)));
+        assert!(visited.visit(Coordinate::new(3, 7)));
+        assert!(!visited.visit(Coordinate::new(3, 7)));
+        assert!(visited.is_visited(Coordinate::new(3, 7)));
+        assert!(!visited.is_visited(Coordinate::new(7, 3)));
+
+        assert!(visited.visit(Coordinate::new(9, 9)));
+        assert_eq!(visited.count(), 2);
+
+        assert!(!visited.is_visited(Coordinate::new(-1, 0)));
+        assert!(!visited.is_visited(Coordinate::new(10, 0)));
+
+        visited.clear();
+        assert_eq!(visited.count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the grid")]
+    fn test_visit_out_of_bounds() {
+        let mut visited = VisitedGrid::new(3, 3);
+        visited.visit(Coordinate::new(3, 0));
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    hash::{BuildHasher, Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::{misc::DeterministicState, prelude::Coordinate, spatial::grid::Grid2D};
+
+/// A grid with a cached fingerprint, for use as a HashMap key
+///
+/// Hashing a `Grid2D` hashes every cell, which dominates the runtime when
+/// storing millions of grid states (e.g. for cycle detection). `GridKey`
+/// instead hashes only a precomputed `u64` fingerprint that is updated
+/// incrementally whenever a cell is changed through `GridKey::set`.
+///
+/// The fingerprint is the XOR of the hashes of every `(index, value)` pair, so
+/// changing one cell only requires hashing the old and the new value. Equality
+/// still compares the full grids, so fingerprint collisions can't cause wrong
+/// results.
+#[derive(Clone, Debug)]
+pub struct GridKey<T: Clone + Hash> {
+    grid: Grid2D<T>,
+    fingerprint: u64,
+}
+
+impl<T: Clone + Hash> GridKey<T> {
+    pub fn new(grid: Grid2D<T>) -> Self {
+        let fingerprint = grid.rolling_hash();
+
+        Self { grid, fingerprint }
+    }
+
+    /// Returns the cached fingerprint of the grid.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Sets the value at the given coordinate and updates the fingerprint.
+    /// Out-of-bounds accesses are ignored.
+    pub fn set(&mut self, coord: Coordinate, value: T) -> Option<T> {
+        let index = self.grid.index_of(coord)?;
+
+        self.fingerprint ^= cell_hash(index, &self.grid[coord]) ^ cell_hash(index, &value);
+        self.grid.set(coord, value)
+    }
+
+    /// Returns the wrapped grid.
+    pub fn into_inner(self) -> Grid2D<T> {
+        self.grid
+    }
+}
+
+impl<T: Clone + Hash> Grid2D<T> {
+    /// Computes the fingerprint that `GridKey` uses for this grid.
+    ///
+    /// Unlike the `Hash` implementation, this is stable across runs and can be
+    /// updated one cell at a time.
+    #[must_use]
+    pub fn rolling_hash(&self) -> u64 {
+        self.iter()
+            .map(|(_, value)| value)
+            .enumerate()
+            .fold(0, |acc, (index, value)| acc ^ cell_hash(index, value))
+    }
+}
+
+fn cell_hash<T: Hash>(index: usize, value: &T) -> u64 {
+    DeterministicState.hash_one((index, value))
+}
+
+impl<T: Clone + Hash> From<Grid2D<T>> for GridKey<T> {
+    fn from(grid: Grid2D<T>) -> Self {
+        Self::new(grid)
+    }
+}
+
+impl<T: Clone + Hash> Deref for GridKey<T> {
+    type Target = Grid2D<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.grid
+    }
+}
+
+impl<T: Clone + Hash + PartialEq> PartialEq for GridKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fingerprint == other.fingerprint && self.grid == other.grid
+    }
+}
+
+impl<T: Clone + Hash + Eq> Eq for GridKey<T> {}
+
+impl<T: Clone + Hash> Hash for GridKey<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fingerprint.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_is_updated_on_set() {
+        let mut key = GridKey::new(Grid2D::new(3, 3, '.'));
+        let empty = key.fingerprint();
+
+        key.set(Coordinate::new(1, 2), '#');
+
+        assert_ne!(key.fingerprint(), empty);
+        assert_eq!(key.fingerprint(), key.rolling_hash());
+        assert_eq!(key[Coordinate::new(1, 2)], '#');
+
+        key.set(Coordinate::new(1, 2), '.');
+        assert_eq!(key.fingerprint(), empty);
+
+        assert_eq!(key.set(Coordinate::new(5, 5), '#'), None);
+        assert_eq!(key.fingerprint(), empty);
+    }
+
+    #[test]
+    fn test_grid_key_as_hash_set_key() {
+        let mut seen = HashSet::default();
+        let mut key = GridKey::new(Grid2D::new(2, 2, 0));
+
+        assert!(seen.insert(key.clone()));
+
+        key.set(Coordinate::new(0, 0), 1);
+        assert!(seen.insert(key.clone()));
+
+        key.set(Coordinate::new(0, 0), 0);
+        assert!(!seen.insert(key));
+    }
+}
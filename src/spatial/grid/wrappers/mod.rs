@@ -1,3 +1,5 @@
 pub mod bool_grid;
+pub mod grid_key;
 
 pub use bool_grid::*;
+pub use grid_key::*;
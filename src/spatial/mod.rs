@@ -1,12 +1,22 @@
 use num::Bounded;
 
+use crate::prelude::{Counter, HashSet};
+
+pub mod connectivity;
 pub mod coordinate;
+pub mod coordinate3d;
 pub mod direction;
 pub mod grid;
+pub mod sparse_grid;
+pub mod turtle;
 
+pub use connectivity::*;
 pub use coordinate::*;
+pub use coordinate3d::*;
 pub use direction::*;
 pub use grid::*;
+pub use sparse_grid::*;
+pub use turtle::*;
 
 pub fn bounding_box<T: CoordinateNum + Bounded>(
     points: impl Iterator<Item = Coordinate<T>>,
@@ -31,10 +41,259 @@ pub fn bounding_box<T: CoordinateNum + Bounded>(
     (Coordinate::new(min_x, min_y), Coordinate::new(max_x, max_y))
 }
 
+/// Returns an iterator over every coordinate in the rectangle spanned by the
+/// two corners, in row-major order.
+///
+/// Both corners are inclusive, and they may be given in any order: the
+/// rectangle always spans from the smaller to the larger x and y values.
+pub fn rect_coords(
+    top_left: Coordinate,
+    bottom_right: Coordinate,
+) -> impl Iterator<Item = Coordinate> {
+    let (min_x, max_x) = (
+        top_left.x.min(bottom_right.x),
+        top_left.x.max(bottom_right.x),
+    );
+    let (min_y, max_y) = (
+        top_left.y.min(bottom_right.y),
+        top_left.y.max(bottom_right.y),
+    );
+
+    (min_y..=max_y).flat_map(move |y| (min_x..=max_x).map(move |x| Coordinate::new(x, y)))
+}
+
+/// Draws every segment onto the grid and returns how many cells are covered
+/// by at least `min_overlap` of them (e.g. the overlapping hydrothermal vents
+/// of AoC 2021, day 5).
+///
+/// Segments include both of their endpoints and are rasterized with
+/// [`Coordinate::line_to`]. Only horizontal, vertical and 45° diagonal
+/// segments are supported. For other slopes, the result is unspecified, and
+/// debug builds panic.
+#[must_use]
+pub fn covered_cells(segments: &[(Coordinate, Coordinate)], min_overlap: usize) -> usize {
+    debug_assert!(
+        segments.iter().all(|&(from, to)| {
+            let d = to - from;
+            d.x == 0 || d.y == 0 || d.x.abs() == d.y.abs()
+        }),
+        "Only orthogonal and diagonal segments are supported"
+    );
+
+    let coverage: Counter<Coordinate> = segments
+        .iter()
+        .flat_map(|&(from, to)| from.line_to(to))
+        .collect();
+
+    coverage
+        .values()
+        .filter(|&&count| count >= min_overlap)
+        .count()
+}
+
+/// Counts the corners of a region, which is the same as the number of
+/// straight sides of its outline (including the outlines of any holes).
+///
+/// Each cell is checked for convex corners (both orthogonal neighbors
+/// towards a diagonal are outside the region) and concave corners (both
+/// are inside, but the diagonal neighbor is not). Cells that only touch
+/// diagonally each contribute their own corner there.
+#[must_use]
+pub fn region_corners(region: &[Coordinate]) -> usize {
+    let cells: HashSet<Coordinate> = region.iter().copied().collect();
+    let diagonals = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+    cells
+        .iter()
+        .map(|&cell| {
+            diagonals
+                .iter()
+                .filter(|&&(dx, dy)| {
+                    let horizontal = cells.contains(&(cell + Coordinate::new(dx, 0)));
+                    let vertical = cells.contains(&(cell + Coordinate::new(0, dy)));
+                    let diagonal = cells.contains(&(cell + Coordinate::new(dx, dy)));
+
+                    (!horizontal && !vertical) || (horizontal && vertical && !diagonal)
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// Translates the points so that the smallest x and y coordinates become zero.
+///
+/// Returns the offset that was subtracted from every point (the minimum corner
+/// of the bounding box), or the origin if there are no points.
+pub fn normalize_to_origin<T: CoordinateNum + Bounded>(
+    points: &mut [Coordinate<T>],
+) -> Coordinate<T> {
+    if points.is_empty() {
+        return Coordinate::new(T::zero(), T::zero());
+    }
+
+    let (min, _) = bounding_box(points.iter().copied());
+
+    for point in points.iter_mut() {
+        *point = Coordinate::new(point.x - min.x, point.y - min.y);
+    }
+
+    min
+}
+
+/// Approximates the geometric median of the points, i.e. the point that
+/// minimizes the sum of Euclidean distances to all of them, using Weiszfeld's
+/// algorithm.
+///
+/// If an iterate lands exactly on one of the input points, the Vardi-Zhang
+/// modification is used to avoid dividing by zero.
+///
+/// Panics if there are no points.
+pub fn geometric_median(points: &[Coordinate<f64>], iterations: usize) -> Coordinate<f64> {
+    assert!(
+        !points.is_empty(),
+        "Cannot compute the median of zero points"
+    );
+
+    let n = points.len() as f64;
+    let mut y = Coordinate::new(
+        points.iter().map(|p| p.x).sum::<f64>() / n,
+        points.iter().map(|p| p.y).sum::<f64>() / n,
+    );
+
+    for _ in 0..iterations {
+        // Weighted mean of the points that do not coincide with y
+        let mut weights = 0.0;
+        let mut tx = 0.0;
+        let mut ty = 0.0;
+
+        // Sum of unit vectors from y towards the points (for Vardi-Zhang)
+        let mut rx = 0.0;
+        let mut ry = 0.0;
+
+        let mut coincident = 0.0;
+
+        for p in points {
+            let d = ((p.x - y.x).powi(2) + (p.y - y.y).powi(2)).sqrt();
+
+            if d < 1e-12 {
+                coincident += 1.0;
+                continue;
+            }
+
+            weights += 1.0 / d;
+            tx += p.x / d;
+            ty += p.y / d;
+            rx += (p.x - y.x) / d;
+            ry += (p.y - y.y) / d;
+        }
+
+        if weights == 0.0 {
+            // All points coincide with y
+            break;
+        }
+
+        let t = Coordinate::new(tx / weights, ty / weights);
+
+        let next = if coincident == 0.0 {
+            t
+        } else {
+            let r = (rx * rx + ry * ry).sqrt();
+
+            if r <= coincident {
+                // y is the geometric median
+                break;
+            }
+
+            let step = coincident / r;
+
+            Coordinate::new(
+                (1.0 - step) * t.x + step * y.x,
+                (1.0 - step) * t.y + step * y.y,
+            )
+        };
+
+        if (next.x - y.x).abs() < 1e-12 && (next.y - y.y).abs() < 1e-12 {
+            y = next;
+            break;
+        }
+
+        y = next;
+    }
+
+    y
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_rect_coords() {
+        let expected = vec![
+            Coordinate::new(1, -1),
+            Coordinate::new(2, -1),
+            Coordinate::new(1, 0),
+            Coordinate::new(2, 0),
+            Coordinate::new(1, 1),
+            Coordinate::new(2, 1),
+        ];
+
+        let coords = rect_coords(Coordinate::new(1, -1), Coordinate::new(2, 1)).collect::<Vec<_>>();
+        assert_eq!(coords, expected);
+
+        let coords = rect_coords(Coordinate::new(2, 1), Coordinate::new(1, -1)).collect::<Vec<_>>();
+        assert_eq!(coords, expected);
+
+        let coords = rect_coords(Coordinate::new(5, 5), Coordinate::new(5, 5)).collect::<Vec<_>>();
+        assert_eq!(coords, vec![Coordinate::new(5, 5)]);
+    }
+
+    #[test]
+    fn test_covered_cells() {
+        // AoC 2021, day 5 example
+        let segments = [
+            ((0, 9), (5, 9)),
+            ((8, 0), (0, 8)),
+            ((9, 4), (3, 4)),
+            ((2, 2), (2, 1)),
+            ((7, 0), (7, 4)),
+            ((6, 4), (2, 0)),
+            ((0, 9), (2, 9)),
+            ((3, 4), (1, 4)),
+            ((0, 0), (8, 8)),
+            ((5, 5), (8, 2)),
+        ]
+        .map(|(a, b)| (Coordinate::from(a), Coordinate::from(b)));
+
+        assert_eq!(covered_cells(&segments, 2), 12);
+
+        let orthogonal = segments
+            .iter()
+            .copied()
+            .filter(|(a, b)| a.x == b.x || a.y == b.y)
+            .collect::<Vec<_>>();
+
+        assert_eq!(covered_cells(&orthogonal, 2), 5);
+        assert_eq!(covered_cells(&orthogonal, 1), 21);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Only orthogonal and diagonal segments are supported")]
+    fn test_covered_cells_rejects_other_slopes() {
+        let _ = covered_cells(&[(Coordinate::new(0, 0), Coordinate::new(2, 1))], 1);
+    }
+
+    #[test]
+    fn test_region_corners() {
+        let square = rect_coords(Coordinate::new(0, 0), Coordinate::new(2, 2)).collect::<Vec<_>>();
+        assert_eq!(region_corners(&square), 4);
+
+        // Two cells touching diagonally
+        let diagonal = [Coordinate::new(0, 0), Coordinate::new(1, 1)];
+        assert_eq!(region_corners(&diagonal), 8);
+    }
+
     #[test]
     fn test_bounding_box() {
         let points = vec![(-1, 0), (1, 1), (2, 2)];
@@ -42,4 +301,76 @@ mod tests {
         assert_eq!(min, Coordinate::new(-1, 0));
         assert_eq!(max, Coordinate::new(2, 2));
     }
+
+    #[test]
+    fn test_normalize_to_origin() {
+        let mut points = vec![
+            Coordinate::new(-3, 5),
+            Coordinate::new(2, 7),
+            Coordinate::new(0, 6),
+        ];
+
+        let offset = normalize_to_origin(&mut points);
+
+        assert_eq!(offset, Coordinate::new(-3, 5));
+        assert_eq!(
+            points,
+            vec![
+                Coordinate::new(0, 0),
+                Coordinate::new(5, 2),
+                Coordinate::new(3, 1)
+            ]
+        );
+
+        let mut empty: Vec<Coordinate<i64>> = vec![];
+        assert_eq!(normalize_to_origin(&mut empty), Coordinate::new(0, 0));
+    }
+
+    #[test]
+    fn test_geometric_median() {
+        // Square: the median is the center
+        let points = [
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(2.0, 0.0),
+            Coordinate::new(0.0, 2.0),
+            Coordinate::new(2.0, 2.0),
+        ];
+
+        let median = geometric_median(&points, 100);
+        assert!((median.x - 1.0).abs() < 1e-6 && (median.y - 1.0).abs() < 1e-6);
+
+        // Unlike the centroid, the median is not pulled towards the outlier
+        let points = [
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(1.0, 0.0),
+            Coordinate::new(0.0, 1.0),
+            Coordinate::new(100.0, 100.0),
+        ];
+
+        let median = geometric_median(&points, 1000);
+        assert!(median.x < 1.0 && median.y < 1.0);
+    }
+
+    #[test]
+    fn test_geometric_median_on_input_point() {
+        // The centroid is (1, 0), which is an input point and the median
+        let points = [
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(1.0, 0.0),
+            Coordinate::new(2.0, 0.0),
+        ];
+
+        let median = geometric_median(&points, 100);
+        assert!((median.x - 1.0).abs() < 1e-9 && median.y.abs() < 1e-9);
+
+        // Triangle with an angle >= 120 degrees: the median is the obtuse vertex
+        let points = [
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(10.0, 0.1),
+            Coordinate::new(-10.0, 0.1),
+        ];
+
+        let median = geometric_median(&points, 1000);
+        assert!(median.x.abs() < 1e-6 && median.y.abs() < 1e-6);
+    }
 }
@@ -0,0 +1,221 @@
+use std::collections::hash_map::Entry;
+
+use crate::prelude::{bounding_box, Connectivity, Coordinate, Grid2D, HashMap};
+
+/// A grid that only stores the cells that have been set, backed by a HashMap.
+///
+/// This is useful for unbounded or very sparse grids (e.g. falling sand on an
+/// infinite plane), where a [`Grid2D`] would be too large or would have to be
+/// resized all the time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coordinate, T>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates an empty grid.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::default(),
+        }
+    }
+
+    /// Returns the number of cells that are set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether no cell is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the value at the given coordinate, if it is set.
+    #[must_use]
+    pub fn get(&self, coord: Coordinate) -> Option<&T> {
+        self.cells.get(&coord)
+    }
+
+    /// Returns a mutable reference to the value at the given coordinate, if it
+    /// is set.
+    #[must_use]
+    pub fn get_mut(&mut self, coord: Coordinate) -> Option<&mut T> {
+        self.cells.get_mut(&coord)
+    }
+
+    /// Sets the value at the given coordinate and returns the previous value.
+    pub fn set(&mut self, coord: Coordinate, value: T) -> Option<T> {
+        self.cells.insert(coord, value)
+    }
+
+    /// Unsets the cell at the given coordinate and returns its value.
+    pub fn remove(&mut self, coord: Coordinate) -> Option<T> {
+        self.cells.remove(&coord)
+    }
+
+    /// Returns the entry for the given coordinate for in-place manipulation.
+    pub fn entry(&mut self, coord: Coordinate) -> Entry<'_, Coordinate, T> {
+        self.cells.entry(coord)
+    }
+
+    /// Returns an iterator over the cells that are set, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        self.cells.iter().map(|(&coord, value)| (coord, value))
+    }
+
+    /// Returns an iterator over the neighbors of the given coordinate that are
+    /// set, and their values.
+    pub fn neighbors_of(
+        &self,
+        coord: Coordinate,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        connectivity.directions().filter_map(move |dir| {
+            let neighbor = coord + dir;
+            self.get(neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    /// Returns the von Neumann neighbors of the given coordinate that are set.
+    pub fn neighbors(&self, coord: Coordinate) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        self.neighbors_of(coord, Connectivity::VonNeumann)
+    }
+
+    /// Returns the Moore neighbors of the given coordinate that are set.
+    pub fn moore_neighbors(
+        &self,
+        coord: Coordinate,
+    ) -> impl Iterator<Item = (Coordinate, &T)> + '_ {
+        self.neighbors_of(coord, Connectivity::Moore)
+    }
+
+    /// Returns the top-left and bottom-right corners (inclusive) of the
+    /// smallest rectangle that contains every cell that is set, or `None` if
+    /// the grid is empty.
+    ///
+    /// This is computed on demand and takes O(n) time.
+    #[must_use]
+    pub fn bounding_box(&self) -> Option<(Coordinate, Coordinate)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(bounding_box(self.cells.keys().copied()))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Materializes the grid as a [`Grid2D`] that is just large enough to hold
+    /// every cell that is set. Cells that are not set become `default`.
+    ///
+    /// Returns the grid and the coordinate of its top-left corner, which has to
+    /// be subtracted from a coordinate to get its position in the dense grid
+    /// (see [`Grid2D::from_coordinates`]).
+    ///
+    /// Panics if the grid is empty.
+    #[must_use]
+    pub fn to_dense(&self, default: T) -> (Grid2D<T>, Coordinate) {
+        let (min, max) = self
+            .bounding_box()
+            .expect("Cannot create a dense grid from an empty SparseGrid");
+
+        let mut grid = Grid2D::new(
+            (max.x - min.x + 1) as usize,
+            (max.y - min.y + 1) as usize,
+            default,
+        );
+
+        for (coord, value) in self.iter() {
+            grid[coord - min] = value.clone();
+        }
+
+        (grid, min)
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Coordinate, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coordinate, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = SparseGrid::new();
+
+        assert!(grid.is_empty());
+        assert_eq!(grid.set(Coordinate::new(-100, 5), 'a'), None);
+        assert_eq!(grid.set(Coordinate::new(-100, 5), 'b'), Some('a'));
+        assert_eq!(grid.get(Coordinate::new(-100, 5)), Some(&'b'));
+        assert_eq!(grid.get(Coordinate::new(0, 0)), None);
+
+        *grid.entry(Coordinate::new(0, 0)).or_insert('x') = 'y';
+        assert_eq!(grid.get(Coordinate::new(0, 0)), Some(&'y'));
+        assert_eq!(grid.len(), 2);
+
+        assert_eq!(grid.remove(Coordinate::new(0, 0)), Some('y'));
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid: SparseGrid<u8> = [
+            (Coordinate::new(1, 0), 1),
+            (Coordinate::new(1, 1), 2),
+            (Coordinate::new(0, 0), 3),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut neighbors = grid.neighbors(Coordinate::new(0, 1)).collect::<Vec<_>>();
+        neighbors.sort();
+        assert_eq!(
+            neighbors,
+            vec![(Coordinate::new(0, 0), &3), (Coordinate::new(1, 1), &2)]
+        );
+
+        assert_eq!(grid.moore_neighbors(Coordinate::new(0, 1)).count(), 3);
+    }
+
+    #[test]
+    fn test_to_dense() {
+        let grid: SparseGrid<char> = [(Coordinate::new(-2, 3), '#'), (Coordinate::new(0, 4), '#')]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            grid.bounding_box(),
+            Some((Coordinate::new(-2, 3), Coordinate::new(0, 4)))
+        );
+
+        let (dense, offset) = grid.to_dense('.');
+
+        let expected: Grid2D<char> = indoc! {"
+            #..
+            ..#
+        "}
+        .into();
+
+        assert_eq!(dense, expected);
+        assert_eq!(offset, Coordinate::new(-2, 3));
+
+        assert_eq!(SparseGrid::<char>::new().bounding_box(), None);
+    }
+}
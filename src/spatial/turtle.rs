@@ -0,0 +1,159 @@
+use crate::prelude::{bounding_box, Coordinate, Direction};
+
+/// A walker with a position and a heading that records every cell it visits,
+/// in the style of turtle graphics.
+///
+/// This is a good fit for puzzles that consist of movement instructions, such
+/// as "turn right and walk 5 steps".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Turtle {
+    position: Coordinate,
+    heading: Direction,
+    trail: Vec<Coordinate>,
+}
+
+impl Turtle {
+    /// Creates a turtle at the given position, facing in the given direction.
+    /// The starting position is the first entry of the trail.
+    #[must_use]
+    pub fn new(position: Coordinate, heading: Direction) -> Self {
+        Self {
+            position,
+            heading,
+            trail: vec![position],
+        }
+    }
+
+    /// Returns the current position
+    #[must_use]
+    pub fn position(&self) -> Coordinate {
+        self.position
+    }
+
+    /// Returns the direction the turtle is facing
+    #[must_use]
+    pub fn heading(&self) -> Direction {
+        self.heading
+    }
+
+    /// Returns every position the turtle has visited, in order, including the
+    /// starting position. Cells that are visited again appear again.
+    #[must_use]
+    pub fn trail(&self) -> &[Coordinate] {
+        &self.trail
+    }
+
+    /// Moves `n` steps in the current heading, recording each step.
+    pub fn forward(&mut self, n: usize) {
+        for _ in 0..n {
+            self.position += self.heading;
+            self.trail.push(self.position);
+        }
+    }
+
+    /// Turns 90 degrees to the left.
+    pub fn turn_left(&mut self) {
+        self.heading = self.heading.turn_left_90();
+    }
+
+    /// Turns 90 degrees to the right.
+    pub fn turn_right(&mut self) {
+        self.heading = self.heading.turn_right_90();
+    }
+
+    /// Turns around to face the opposite direction.
+    pub fn turn_around(&mut self) {
+        self.heading = self.heading.opposite();
+    }
+
+    /// Jumps directly to the given position. Only the target is added to the
+    /// trail, not the cells in between.
+    pub fn goto(&mut self, position: Coordinate) {
+        self.position = position;
+        self.trail.push(position);
+    }
+
+    /// Returns the top-left and bottom-right corners (inclusive) of the
+    /// smallest rectangle that contains the whole trail.
+    #[must_use]
+    pub fn bounding_box(&self) -> (Coordinate, Coordinate) {
+        bounding_box(self.trail.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::HashSet;
+
+    use super::*;
+
+    fn follow(instructions: &str) -> Turtle {
+        let mut turtle = Turtle::new(Coordinate::new(0, 0), Direction::Up);
+
+        for instruction in instructions.split(", ") {
+            let (turn, steps) = instruction.split_at(1);
+
+            match turn {
+                "L" => turtle.turn_left(),
+                _ => turtle.turn_right(),
+            }
+
+            turtle.forward(steps.parse().unwrap());
+        }
+
+        turtle
+    }
+
+    #[test]
+    fn test_aoc_2016_day_1() {
+        let origin = Coordinate::new(0, 0);
+
+        assert_eq!(follow("R2, L3").position().manhattan_distance(origin), 5);
+        assert_eq!(
+            follow("R2, R2, R2").position().manhattan_distance(origin),
+            2
+        );
+        assert_eq!(
+            follow("R5, L5, R5, R3")
+                .position()
+                .manhattan_distance(origin),
+            12
+        );
+
+        // The first location that is visited twice
+        let turtle = follow("R8, R4, R4, R8");
+        let mut seen = HashSet::default();
+        let twice = turtle.trail().iter().find(|&&c| !seen.insert(c)).unwrap();
+
+        assert_eq!(twice.manhattan_distance(origin), 4);
+    }
+
+    #[test]
+    fn test_turns_and_goto() {
+        let mut turtle = Turtle::new(Coordinate::new(1, 1), Direction::Right);
+
+        turtle.forward(2);
+        turtle.turn_around();
+        assert_eq!(turtle.heading(), Direction::Left);
+
+        turtle.goto(Coordinate::new(-4, 7));
+        turtle.forward(1);
+
+        assert_eq!(turtle.position(), Coordinate::new(-5, 7));
+        assert_eq!(
+            turtle.trail(),
+            &[
+                Coordinate::new(1, 1),
+                Coordinate::new(2, 1),
+                Coordinate::new(3, 1),
+                Coordinate::new(-4, 7),
+                Coordinate::new(-5, 7),
+            ]
+        );
+
+        assert_eq!(
+            turtle.bounding_box(),
+            (Coordinate::new(-5, 1), Coordinate::new(3, 7))
+        );
+    }
+}